@@ -15,7 +15,80 @@ export class DataFrame extends pli.DataFrame {
     return DataFrame.wrap((df as any).ptr);
   }
 
+  static override from_records(
+    records: any[],
+    schema?: any,
+    infer_schema_length?: number,
+  ): DataFrame {
+    return DataFrame.__wrap_ptr(
+      super.from_records(records, schema, infer_schema_length),
+    );
+  }
+
+  static override from_columns(columns: object, schema?: any): DataFrame {
+    return DataFrame.__wrap_ptr(super.from_columns(columns, schema));
+  }
+
   override lazy(): LazyFrame {
     return LazyFrame.__wrap_ptr(super.lazy());
   }
+
+  /**
+   * Start a group by operation.
+   * The aggregations are computed in the worker and resolve to a `DataFrame`.
+   * @param by - Column name(s) to group by.
+   * @param maintain_order - Keep the groups in the order they first appear.
+   */
+  group_by(by: string[], maintain_order = false): GroupBy {
+    return new GroupBy((this as any).__group_by(by, maintain_order));
+  }
+}
+
+function collect(lf: pli.LazyFrame): Promise<DataFrame> {
+  return LazyFrame.__wrap_ptr(lf).collect();
+}
+
+export class GroupBy {
+  private gb: pli.GroupBy;
+
+  constructor(gb: pli.GroupBy) {
+    this.gb = gb;
+  }
+
+  agg(aggs: pli.Expr[]): Promise<DataFrame> {
+    return collect(this.gb.agg(aggs));
+  }
+  head(n?: number): Promise<DataFrame> {
+    return collect(this.gb.head(n));
+  }
+  tail(n?: number): Promise<DataFrame> {
+    return collect(this.gb.tail(n));
+  }
+  sum(): Promise<DataFrame> {
+    return collect(this.gb.sum());
+  }
+  mean(): Promise<DataFrame> {
+    return collect(this.gb.mean());
+  }
+  min(): Promise<DataFrame> {
+    return collect(this.gb.min());
+  }
+  max(): Promise<DataFrame> {
+    return collect(this.gb.max());
+  }
+  first(): Promise<DataFrame> {
+    return collect(this.gb.first());
+  }
+  last(): Promise<DataFrame> {
+    return collect(this.gb.last());
+  }
+  n_unique(): Promise<DataFrame> {
+    return collect(this.gb.n_unique());
+  }
+  count(): Promise<DataFrame> {
+    return collect(this.gb.count());
+  }
+  quantile(quantile: number, interpolation: string): Promise<DataFrame> {
+    return collect(this.gb.quantile(quantile, interpolation));
+  }
 }
@@ -15,4 +15,6 @@ await waitForMsgType(POLARS_WORKER, "ready");
 
 export * from "./io.js";
 export * from "./lazy/index.js";
+export const DataType = pli.DataType;
+export const { enable_string_cache, disable_string_cache, using_string_cache } = pli;
 export { DataFrame, Series, LazyFrame, POLARS_WORKER };
@@ -1,39 +1,46 @@
-import { DataFrame, POLARS_WORKER } from "./index.js";
-import { waitForMsgType } from "./utils.js";
-
-const readCsvDefaultOptions = {
-  inferSchemaLength: 100,
-  hasHeader: true,
-  ignoreErrors: true,
-  chunkSize: 10000,
-  skipRows: 0,
-  sep: ",",
-  rechunk: false,
-  encoding: "utf8",
-  lowMemory: false,
-  parseDates: false,
-  skipRowsAfterHeader: 0,
-};
+import { DataFrame, LazyFrame, POLARS_WORKER } from "./index.js";
+import { waitForReply } from "./utils.js";
 
 export interface ReadCsvOptions {
+  separator?: string;
+  /** @deprecated use `separator` */
+  sep?: string;
+  /** @deprecated use `separator` */
   seperator?: string;
+  quoteChar?: string | null;
+  commentPrefix?: string;
+  eolChar?: string;
   hasHeader?: boolean;
-  chunkSize?: number;
+  columns?: string[];
+  projection?: number[];
+  numRows?: number;
   skipRows?: number;
-  sep?: string;
+  skipRowsAfterHeader?: number;
+  chunkSize?: number;
+  /** Number of rows used for schema inference, `null` to scan the whole file. */
+  inferSchemaLength?: number | null;
+  /** A single value, a list of values, or a `{ column: value }` mapping. */
+  nullValues?: string | string[] | Record<string, string>;
+  missingIsNull?: boolean;
+  /** Full schema of the file as a `{ column: dtype }` mapping. */
+  schema?: Record<string, string>;
+  /** Overwrite the inferred dtype of some columns as a `{ column: dtype }` mapping. */
+  schemaOverrides?: Record<string, string>;
+  ignoreErrors?: boolean;
+  truncateRaggedLines?: boolean;
+  decimalComma?: boolean;
+  encoding?: "utf8" | "utf8-lossy";
+  parseDates?: boolean;
   rechunk?: boolean;
-  encoding?: string;
   lowMemory?: boolean;
-  parseDates?: boolean;
-  skipRowsAfterHeader?: number;
-  numRows?: number;
+  nThreads?: number;
+  /** @deprecated use `nThreads` */
   numThreads?: number;
+  rowIndexName?: string;
+  rowIndexOffset?: number;
 }
 
-export async function read_csv(
-  path: string | Uint8Array,
-  options: ReadCsvOptions = readCsvDefaultOptions,
-): Promise<DataFrame> {
+async function toBuffer(path: string | Uint8Array): Promise<Uint8Array> {
   let buf: Uint8Array;
   if (typeof path === "string") {
     try {
@@ -51,17 +58,185 @@ export async function read_csv(
   } else {
     buf = path;
   }
+  return buf;
+}
 
+async function readInWorker(
+  type: string,
+  buf: Uint8Array,
+  options: any,
+): Promise<DataFrame> {
   POLARS_WORKER.postMessage(
     {
-      type: "read_csv",
+      type,
       options,
       buf,
     },
     [buf.buffer],
   );
-  const event: any = await waitForMsgType(POLARS_WORKER, "read_csv");
+  const event = await waitForReply(POLARS_WORKER, type);
   const ptr = event.data.ptr;
 
   return DataFrame.wrap(ptr);
 }
+
+export async function read_csv(
+  path: string | Uint8Array,
+  options: ReadCsvOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_csv", buf, options);
+}
+
+/**
+ * Lazily read a CSV buffer.
+ * Projections, filters and row limits of the query are pushed down into the reader,
+ * so only the needed columns are materialized on `collect`.
+ * The schema is inferred in the worker from at most `inferSchemaLength` rows,
+ * or 10000 rows if it is `null`.
+ */
+export async function scan_csv(
+  path: string | Uint8Array,
+  options: ReadCsvOptions = {},
+): Promise<LazyFrame> {
+  const buf = await toBuffer(path);
+  POLARS_WORKER.postMessage(
+    {
+      type: "scan_csv",
+      options,
+      buf,
+    },
+    [buf.buffer],
+  );
+  const event = await waitForReply(POLARS_WORKER, "scan_csv");
+
+  return LazyFrame.wrap(event.data.ptr);
+}
+
+export interface ReadCsvStreamOptions extends ReadCsvOptions {
+  /** Called with every batch of rows as soon as it is parsed. */
+  onBatch?: (df: DataFrame) => void;
+}
+
+/**
+ * Read CSV from a stream of byte chunks, parsing rows as they arrive.
+ * Peak memory stays close to a single copy of the data.
+ * Compressed streams are rejected; pipe them through a `DecompressionStream` first.
+ */
+export async function read_csv_stream(
+  stream: ReadableStream<Uint8Array>,
+  options: ReadCsvStreamOptions = {},
+): Promise<DataFrame> {
+  const { onBatch, ...readOptions } = options;
+  const onMsg = (event: any) => {
+    if (event.data?.type === "CsvStreamReader::batch") {
+      onBatch?.(DataFrame.wrap(event.data.ptr));
+    }
+  };
+  if (onBatch) {
+    POLARS_WORKER.addEventListener("message", onMsg);
+  }
+  const reader = stream.getReader();
+  try {
+    POLARS_WORKER.postMessage({
+      type: "CsvStreamReader::new",
+      options: readOptions,
+      emitBatches: onBatch !== undefined,
+    });
+    const created = await waitForReply(POLARS_WORKER, "CsvStreamReader::new");
+    const ptr = created.data.ptr;
+
+    while (true) {
+      let result: ReadableStreamReadResult<Uint8Array>;
+      try {
+        result = await reader.read();
+      } catch (e) {
+        POLARS_WORKER.postMessage({ type: "CsvStreamReader::free", ptr });
+        throw e;
+      }
+      if (result.done) break;
+      const chunk = result.value.slice();
+      POLARS_WORKER.postMessage(
+        { type: "CsvStreamReader::push_chunk", ptr, chunk },
+        [chunk.buffer],
+      );
+      await waitForReply(POLARS_WORKER, "CsvStreamReader::push_chunk");
+    }
+
+    POLARS_WORKER.postMessage({ type: "CsvStreamReader::finish", ptr });
+    const event = await waitForReply(POLARS_WORKER, "CsvStreamReader::finish");
+    return DataFrame.wrap(event.data.ptr);
+  } catch (e) {
+    await reader.cancel(e).catch(() => {});
+    throw e;
+  } finally {
+    POLARS_WORKER.removeEventListener("message", onMsg);
+  }
+}
+
+export interface ReadParquetOptions {
+  columns?: string[];
+  numRows?: number;
+  rowIndexName?: string;
+  rowIndexOffset?: number;
+  parallel?: "auto" | "columns" | "row_groups" | "none";
+}
+
+export async function read_parquet(
+  path: string | Uint8Array,
+  options: ReadParquetOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_parquet", buf, options);
+}
+
+export interface ReadIpcOptions {
+  columns?: string[];
+  numRows?: number;
+  rowIndexName?: string;
+  rowIndexOffset?: number;
+}
+
+export async function read_ipc(
+  path: string | Uint8Array,
+  options: ReadIpcOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_ipc", buf, options);
+}
+
+export async function read_ipc_stream(
+  path: string | Uint8Array,
+  options: ReadIpcOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_ipc_stream", buf, options);
+}
+
+export interface ReadJsonOptions {
+  inferSchemaLength?: number;
+  schemaOverrides?: Record<string, string>;
+  batchSize?: number;
+  ignoreErrors?: boolean;
+}
+
+export interface ReadNdjsonOptions extends ReadJsonOptions {
+  numRows?: number;
+  lowMemory?: boolean;
+}
+
+export async function read_json(
+  path: string | Uint8Array,
+  options: ReadJsonOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_json", buf, options);
+}
+
+export async function read_ndjson(
+  path: string | Uint8Array,
+  options: ReadNdjsonOptions = {},
+): Promise<DataFrame> {
+  const buf = await toBuffer(path);
+  return readInWorker("read_ndjson", buf, options);
+}
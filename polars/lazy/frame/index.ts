@@ -1,6 +1,6 @@
 import { DataFrame, POLARS_WORKER } from "../../index.js";
 import * as pli from "../../core/browser.js";
-import { waitForMsgType } from "../../utils.js";
+import { waitForReply } from "../../utils.js";
 
 export class LazyFrame extends pli.LazyFrame {
   private ptr!: number;
@@ -22,10 +22,7 @@ export class LazyFrame extends pli.LazyFrame {
       type: "LazyFrame::collect",
       ptr,
     });
-    const event: any = await waitForMsgType(
-      POLARS_WORKER,
-      "LazyFrame::collect",
-    );
+    const event = await waitForReply(POLARS_WORKER, "LazyFrame::collect");
     const df_ptr = event.data.ptr;
     return DataFrame.wrap(df_ptr);
   }
@@ -39,4 +36,50 @@ export class LazyFrame extends pli.LazyFrame {
   override drop_nulls(subset?: string | string[]): LazyFrame {
     return LazyFrame.__wrap_ptr(super.drop_nulls(subset));
   }
+  override group_by(by: pli.Expr[], maintain_order = false): LazyGroupBy {
+    return LazyGroupBy.__wrap_ptr(super.group_by(by, maintain_order));
+  }
+}
+
+export class LazyGroupBy extends pli.LazyGroupBy {
+  private ptr!: number;
+
+  static wrap(ptr: number): LazyGroupBy {
+    const obj = Object.create(LazyGroupBy.prototype);
+    obj.ptr = ptr;
+
+    return obj;
+  }
+
+  static __wrap_ptr(gb: pli.LazyGroupBy): LazyGroupBy {
+    return LazyGroupBy.wrap((gb as any).ptr);
+  }
+
+  override agg(aggs: pli.Expr[]): LazyFrame {
+    return LazyFrame.__wrap_ptr(super.agg(aggs));
+  }
+  override head(n?: number): LazyFrame {
+    return LazyFrame.__wrap_ptr(super.head(n));
+  }
+  override tail(n?: number): LazyFrame {
+    return LazyFrame.__wrap_ptr(super.tail(n));
+  }
+
+  /**
+   * Apply a function to every group as a `DataFrame` and stack the results.
+   * The groups are computed in the worker, `f` is called on the main thread.
+   *
+   * __It is not recommended to use this, as materializing every group is very expensive.__
+   */
+  async apply(f: (df: DataFrame) => DataFrame): Promise<DataFrame> {
+    POLARS_WORKER.postMessage({
+      type: "LazyGroupBy::partition",
+      ptr: this.ptr,
+    });
+    const event = await waitForReply(POLARS_WORKER, "LazyGroupBy::partition");
+    const groups = event.data.ptrs.map((ptr: number) => f(DataFrame.wrap(ptr)));
+    return DataFrame.__wrap_ptr(
+      (pli.LazyGroupBy as any).__concat_groups(groups),
+    );
+  }
 }
@@ -1,2 +1 @@
-export {col, cols} from "../core/browser.js";
-
+export {col, cols, lit, struct} from "../core/browser.js";
@@ -7,3 +7,12 @@ export function waitForMsgType(target: any, type: any) {
     });
   });
 }
+
+/** Like `waitForMsgType`, but rejects if the worker replied with an error. */
+export async function waitForReply(target: any, type: any): Promise<any> {
+  const event: any = await waitForMsgType(target, type);
+  if (event.data.error !== undefined) {
+    throw new Error(event.data.error);
+  }
+  return event;
+}
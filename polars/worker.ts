@@ -4,7 +4,13 @@
  * any function that uses the rust threadpool needs to be executed in the worker.
  */
 import * as pli from "./core/browser.js";
-import { ReadCsvOptions } from "./io.js";
+import {
+  ReadCsvOptions,
+  ReadIpcOptions,
+  ReadJsonOptions,
+  ReadNdjsonOptions,
+  ReadParquetOptions,
+} from "./io.js";
 
 let initialized = false;
 
@@ -17,25 +23,96 @@ export async function start(mem: WebAssembly.Memory) {
 }
 
 
+/** Reply to a request with the error it failed with, so the caller can reject. */
+function postError(type: string, e: unknown): void {
+  const error = e instanceof Error ? e.message : String(e);
+  return postMessage({ type, error });
+}
+
 function read_csv(buf: Uint8Array, options: ReadCsvOptions): void {
+  const ptr = (pli.read_csv(buf, options) as any).ptr;
+  return postMessage({
+    type: "read_csv",
+    ptr,
+  });
+}
+
+function scan_csv(buf: Uint8Array, options: ReadCsvOptions): void {
+  const ptr = (pli.scan_csv(buf, options) as any).ptr;
+  return postMessage({
+    type: "scan_csv",
+    ptr,
+  });
+}
+
+function read_parquet(buf: Uint8Array, options: ReadParquetOptions): void {
+  const ptr = (
+    pli.read_parquet(
+      buf,
+      options.columns,
+      options.numRows,
+      options.rowIndexName,
+      options.rowIndexOffset ?? 0,
+      options.parallel ?? "auto",
+    ) as any
+  ).ptr;
+  return postMessage({
+    type: "read_parquet",
+    ptr,
+  });
+}
+
+function read_ipc(
+  type: "read_ipc" | "read_ipc_stream",
+  buf: Uint8Array,
+  options: ReadIpcOptions,
+): void {
+  const reader = type === "read_ipc" ? pli.read_ipc : pli.read_ipc_stream;
+  const ptr = (
+    reader(
+      buf,
+      options.columns,
+      options.numRows,
+      options.rowIndexName,
+      options.rowIndexOffset ?? 0,
+    ) as any
+  ).ptr;
+  return postMessage({
+    type,
+    ptr,
+  });
+}
+
+function read_json(buf: Uint8Array, options: ReadJsonOptions): void {
   const ptr = (
-    pli.read_csv(
+    pli.read_json(
       buf,
-      options.seperator ?? ",",
-      options.chunkSize ?? 10000,
-      options.hasHeader ?? true,
+      options.inferSchemaLength ?? 100,
+      options.schemaOverrides,
+      options.batchSize ?? 8192,
+      options.ignoreErrors ?? false,
+    ) as any
+  ).ptr;
+  return postMessage({
+    type: "read_json",
+    ptr,
+  });
+}
+
+function read_ndjson(buf: Uint8Array, options: ReadNdjsonOptions): void {
+  const ptr = (
+    pli.read_ndjson(
+      buf,
+      options.inferSchemaLength ?? 100,
+      options.schemaOverrides,
+      options.batchSize,
       options.numRows,
-      options.skipRows ?? 0,
-      options.rechunk ?? false,
-      options.encoding ?? "utf8",
-      options.numThreads,
+      options.ignoreErrors ?? false,
       options.lowMemory ?? false,
-      options.parseDates ?? false,
-      options.skipRowsAfterHeader ?? 0,
     ) as any
   ).ptr;
   return postMessage({
-    type: "read_csv",
+    type: "read_ndjson",
     ptr,
   });
 }
@@ -47,15 +124,119 @@ self.addEventListener("message", async (event) => {
     }
     case "read_csv": {
       const { options } = event.data;
-      return read_csv(event.data.buf, options);
+      try {
+        return read_csv(event.data.buf, options);
+      } catch (e) {
+        return postError("read_csv", e);
+      }
+    }
+    case "scan_csv": {
+      const { options } = event.data;
+      try {
+        return scan_csv(event.data.buf, options);
+      } catch (e) {
+        return postError("scan_csv", e);
+      }
+    }
+    case "read_parquet": {
+      const { options } = event.data;
+      try {
+        return read_parquet(event.data.buf, options);
+      } catch (e) {
+        return postError("read_parquet", e);
+      }
+    }
+    case "read_ipc":
+    case "read_ipc_stream": {
+      const { options } = event.data;
+      try {
+        return read_ipc(event.data.type, event.data.buf, options);
+      } catch (e) {
+        return postError(event.data.type, e);
+      }
+    }
+    case "read_json": {
+      const { options } = event.data;
+      try {
+        return read_json(event.data.buf, options);
+      } catch (e) {
+        return postError("read_json", e);
+      }
+    }
+    case "read_ndjson": {
+      const { options } = event.data;
+      try {
+        return read_ndjson(event.data.buf, options);
+      } catch (e) {
+        return postError("read_ndjson", e);
+      }
+    }
+    case "CsvStreamReader::new": {
+      const onBatch = event.data.emitBatches
+        ? (df: any) =>
+            postMessage({ type: "CsvStreamReader::batch", ptr: df.ptr })
+        : undefined;
+      try {
+        const reader = new pli.CsvStreamReader(event.data.options, onBatch);
+        return postMessage({
+          type: "CsvStreamReader::new",
+          ptr: (reader as any).ptr,
+        });
+      } catch (e) {
+        return postError("CsvStreamReader::new", e);
+      }
+    }
+    case "CsvStreamReader::push_chunk": {
+      const reader = (pli.CsvStreamReader as any).__wrap(event.data.ptr);
+      try {
+        const rows = reader.push_chunk(event.data.chunk);
+        return postMessage({ type: "CsvStreamReader::push_chunk", rows });
+      } catch (e) {
+        reader.free();
+        return postError("CsvStreamReader::push_chunk", e);
+      }
+    }
+    case "CsvStreamReader::finish": {
+      const reader = (pli.CsvStreamReader as any).__wrap(event.data.ptr);
+      try {
+        const df = reader.finish();
+        return postMessage({
+          type: "CsvStreamReader::finish",
+          ptr: df.ptr,
+        });
+      } catch (e) {
+        return postError("CsvStreamReader::finish", e);
+      } finally {
+        reader.free();
+      }
+    }
+    case "CsvStreamReader::free": {
+      const reader = (pli.CsvStreamReader as any).__wrap(event.data.ptr);
+      return reader.free();
+    }
+    case "LazyGroupBy::partition": {
+      const gb = (pli.LazyGroupBy as any).__wrap(event.data.ptr);
+      try {
+        const groups = gb.__partition_from_worker();
+        return postMessage({
+          type: "LazyGroupBy::partition",
+          ptrs: groups.map((df: any) => df.ptr),
+        });
+      } catch (e) {
+        return postError("LazyGroupBy::partition", e);
+      }
     }
     case "LazyFrame::collect": {
       const lf = (pli.LazyFrame as any).__wrap(event.data.ptr);
-      const df = await lf.__collect_from_worker();
-      return postMessage({
-        type: "LazyFrame::collect",
-        ptr: df.ptr,
-      });
+      try {
+        const df = await lf.__collect_from_worker();
+        return postMessage({
+          type: "LazyFrame::collect",
+          ptr: df.ptr,
+        });
+      } catch (e) {
+        return postError("LazyFrame::collect", e);
+      }
     }
 
     default: {
@@ -8,6 +8,19 @@ pub trait RefRustStruct<'a, T: RefFromWasmAbi<Abi = u32>> {
     fn ref_rust(self) -> wasm_bindgen::__rt::RcRef<T>;
 }
 
+/// Whether `jsv` is a live object of the exported class with prototype `proto`,
+/// or of a JS subclass of it.
+///
+/// The prototype chain is checked instead of the constructor name, which minifiers
+/// rename, and freed objects are rejected as their `ptr` is reset to 0.
+pub fn is_live_instance(jsv: &wasm_bindgen::JsValue, proto: &js_sys::Object) -> bool {
+    proto.is_prototype_of(jsv)
+        && js_sys::Reflect::get(jsv, &wasm_bindgen::JsValue::from_str("ptr"))
+            .ok()
+            .and_then(|ptr| ptr.as_f64())
+            .is_some_and(|ptr| ptr != 0.0)
+}
+
 #[macro_export]
 macro_rules! extern_struct {
     ( $x:ident, $y:ty) => {
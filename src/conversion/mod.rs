@@ -1,14 +1,14 @@
 pub mod extern_iterator;
 pub mod extern_struct;
-pub mod from;
-pub mod prelude;
 
-use crate::JsResult;
+use crate::{datatypes::TypedArrayType, error::JsPolarsErr, series::js_value_to_series, JsResult};
 use polars::prelude::*;
 use wasm_bindgen::convert::FromWasmAbi;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{describe::WasmDescribe, JsCast};
 
+const MS_IN_DAY: f64 = 86_400_000.0;
+
 #[repr(transparent)]
 pub struct Wrap<T>(pub T);
 
@@ -26,30 +26,6 @@ impl<T> From<T> for Wrap<T> {
     }
 }
 
-impl WasmDescribe for Wrap<QuantileInterpolOptions> {
-    fn describe() {
-        wasm_bindgen::describe::inform(wasm_bindgen::describe::STRING)
-    }
-}
-
-impl FromWasmAbi for Wrap<QuantileInterpolOptions> {
-    type Abi = <Vec<u8> as FromWasmAbi>::Abi;
-
-    #[inline]
-    unsafe fn from_abi(js: Self::Abi) -> Self {
-        let s = String::from_utf8_unchecked(<Vec<u8>>::from_abi(js));
-        let interpol = match s.as_ref() {
-            "nearest" => QuantileInterpolOptions::Nearest,
-            "lower" => QuantileInterpolOptions::Lower,
-            "higher" => QuantileInterpolOptions::Higher,
-            "midpoint" => QuantileInterpolOptions::Midpoint,
-            "linear" => QuantileInterpolOptions::Linear,
-            _ => panic!("not supported"),
-        };
-        interpol.into()
-    }
-}
-
 impl WasmDescribe for Wrap<AnyValue<'_>> {
     fn describe() {
         wasm_bindgen::describe::inform(wasm_bindgen::describe::ENUM)
@@ -67,17 +43,21 @@ impl FromWasmAbi for Wrap<AnyValue<'_>> {
 }
 
 pub trait FromJsValue: Sized + Send {
-    fn from_js(obj: JsValue) -> Self;
+    fn try_from_js(obj: JsValue) -> JsResult<Self>;
+
+    fn from_js(obj: JsValue) -> Self {
+        Self::try_from_js(obj).unwrap_throw()
+    }
 }
 
 impl FromJsValue for AnyValue<'_> {
-    fn from_js(jsv: JsValue) -> Self {
+    fn try_from_js(jsv: JsValue) -> JsResult<Self> {
         if jsv.is_null() || jsv.is_undefined() {
-            return AnyValue::Null;
+            return Ok(AnyValue::Null);
         }
         let ty = jsv.js_typeof().as_string().unwrap();
 
-        match ty.as_ref() {
+        let av = match ty.as_ref() {
             "boolean" => {
                 let b: bool = js_sys::Boolean::unchecked_from_js(jsv).into();
                 AnyValue::Boolean(b)
@@ -88,25 +68,165 @@ impl FromJsValue for AnyValue<'_> {
             }
             "string" => {
                 let s: String = js_sys::JsString::unchecked_from_js(jsv).into();
-                AnyValue::String(Box::leak::<'_>(s.into_boxed_str()))
+                AnyValue::StringOwned(s.into())
             }
             "bigint" => {
-                let num = jsv.as_string().unwrap().parse::<u64>().unwrap();
-                AnyValue::UInt64(num)
+                let s: String = js_sys::BigInt::unchecked_from_js(jsv).to_string(10)?.into();
+                match s.parse::<i64>() {
+                    Ok(num) => AnyValue::Int64(num),
+                    Err(_) => {
+                        let num = s.parse::<u64>().map_err(|_| {
+                            JsPolarsErr::Other(format!("bigint {} does not fit in 64 bits", s))
+                        })?;
+                        AnyValue::UInt64(num)
+                    }
+                }
             }
             _ => {
                 if js_sys::Date::is_type_of(&jsv) {
                     let js_date = js_sys::Date::unchecked_from_js(jsv);
-                    let ms = js_date.get_milliseconds();
+                    let ms = js_date.get_time();
 
                     AnyValue::Datetime(ms as i64, TimeUnit::Milliseconds, &None)
+                } else if js_sys::ArrayBuffer::is_view(&jsv)
+                    && !jsv.is_instance_of::<js_sys::DataView>()
+                {
+                    AnyValue::List(typed_array_to_series(jsv.into()))
+                } else if let Some(series) = js_value_to_series(&jsv) {
+                    AnyValue::List(series)
                 } else if js_sys::Array::is_array(&jsv) {
-                    todo!()
+                    let arr: js_sys::Array = jsv.unchecked_into();
+                    AnyValue::List(js_array_to_series("", &arr, None, false)?)
+                } else if jsv.is_object() && !jsv.is_function() {
+                    object_to_struct(jsv.unchecked_ref())?
                 } else {
-                    todo!()
+                    let msg = format!("cannot convert {:?} to a value", jsv);
+                    return Err(JsPolarsErr::Other(msg).into());
                 }
             }
+        };
+        Ok(av)
+    }
+}
+
+/// Convert the entries of a JS object to a struct value, inferring the field dtypes.
+fn object_to_struct(obj: &js_sys::Object) -> JsResult<AnyValue<'static>> {
+    let entries = js_sys::Object::entries(obj);
+    let mut values = Vec::with_capacity(entries.length() as usize);
+    let mut fields = Vec::with_capacity(entries.length() as usize);
+    for entry in entries.iter() {
+        let entry: js_sys::Array = entry.unchecked_into();
+        let name = entry.get(0).as_string().unwrap_or_default();
+        let av = AnyValue::try_from_js(entry.get(1))?;
+        fields.push(Field::new(name.into(), av.dtype()));
+        values.push(av);
+    }
+    Ok(AnyValue::StructOwned(Box::new((values, fields))))
+}
+
+/// Build a series from a JS array.
+///
+/// Without a dtype, the dtype is inferred from the values. If `strict`, all values must have
+/// the same type, otherwise the values are cast to their supertype.
+/// With a dtype, every value is converted to it. If `strict`, values that do not match the
+/// dtype raise an error, otherwise they become null.
+pub(crate) fn js_array_to_series(
+    name: &str,
+    values: &js_sys::Array,
+    dtype: Option<&DataType>,
+    strict: bool,
+) -> JsResult<Series> {
+    let avs = values
+        .iter()
+        .map(AnyValue::try_from_js)
+        .collect::<JsResult<Vec<_>>>()?;
+    any_values_to_series(name, &avs, dtype, strict)
+}
+
+/// Build a series from values that were read from JS, see [`js_array_to_series`].
+pub(crate) fn any_values_to_series(
+    name: &str,
+    avs: &[AnyValue],
+    dtype: Option<&DataType>,
+    strict: bool,
+) -> JsResult<Series> {
+    let s = match dtype {
+        None => Series::from_any_values(name.into(), avs, strict),
+        Some(dtype) => {
+            let mut values = Vec::with_capacity(avs.len());
+            for (idx, av) in avs.iter().enumerate() {
+                match coerce_any_value(av.clone(), dtype) {
+                    Some(av) => values.push(av),
+                    None if strict => {
+                        let msg = format!(
+                            "cannot convert value {} at index {} of Series '{}' to {}",
+                            av, idx, name, dtype
+                        );
+                        return Err(JsPolarsErr::Other(msg).into());
+                    }
+                    None => values.push(AnyValue::Null),
+                }
+            }
+            Series::from_any_values_and_dtype(name.into(), &values, dtype, strict)
+        }
+    };
+    let s = s.map_err(JsPolarsErr::from)?;
+    Ok(s)
+}
+
+/// Convert a value that was read from JS to `dtype`, or return `None` if it does not match.
+///
+/// JS numbers are only accepted for integer dtypes if they have no fractional part,
+/// and strings are only accepted for string and categorical dtypes.
+fn coerce_any_value<'a>(av: AnyValue<'a>, dtype: &'a DataType) -> Option<AnyValue<'a>> {
+    match (av, dtype) {
+        (AnyValue::Null, _) => Some(AnyValue::Null),
+        (AnyValue::Float64(v), dt) if dt.is_integer() && v.fract() != 0.0 => None,
+        (AnyValue::Boolean(v), DataType::Boolean) => Some(AnyValue::Boolean(v)),
+        (AnyValue::Boolean(_), _) => None,
+        (_, DataType::Boolean) => None,
+        (av @ AnyValue::StringOwned(_), DataType::String)
+        | (av @ AnyValue::StringOwned(_), DataType::Categorical(_, _))
+        | (av @ AnyValue::StringOwned(_), DataType::Enum(_, _)) => Some(av),
+        (_, DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _)) => None,
+        (av, dt) if dt.is_numeric() && !av.is_numeric() => None,
+        (AnyValue::List(s), DataType::List(inner)) => s.strict_cast(inner).ok().map(AnyValue::List),
+        (AnyValue::List(s), DataType::Array(inner, width)) if s.len() == *width => s
+            .strict_cast(inner)
+            .ok()
+            .map(|s| AnyValue::Array(s, *width)),
+        (AnyValue::StructOwned(payload), DataType::Struct(fields)) => {
+            let (values, av_fields) = *payload;
+            let values = fields
+                .iter()
+                .map(|field| {
+                    let av = av_fields
+                        .iter()
+                        .position(|f| f.name() == field.name())
+                        .map(|idx| values[idx].clone())
+                        .unwrap_or(AnyValue::Null);
+                    coerce_any_value(av, field.data_type())
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(AnyValue::StructOwned(Box::new((values, fields.clone()))))
         }
+        (av, dt) => av.strict_cast(dt),
+    }
+}
+
+fn typed_array_to_series(arr: TypedArrayType) -> Series {
+    match arr {
+        TypedArrayType::Int8(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Uint8(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Uint8Clamped(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Int16(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Uint16(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Int32(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Uint32(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Float32(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::Float64(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::BigInt64(arr) => Series::new("".into(), arr.to_vec()),
+        TypedArrayType::BigUint64(arr) => Series::new("".into(), arr.to_vec()),
     }
 }
 
@@ -126,6 +246,7 @@ impl From<Wrap<AnyValue<'_>>> for JsValue {
             AnyValue::Null => JsValue::null(),
             AnyValue::Boolean(v) => v.into(),
             AnyValue::String(v) => v.into(),
+            AnyValue::StringOwned(v) => v.as_str().into(),
             AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
                 let s = if arr.is_null() {
                     rev.get(idx)
@@ -135,20 +256,67 @@ impl From<Wrap<AnyValue<'_>>> for JsValue {
                 s.into()
             }
             AnyValue::Date(v) => {
-                let jsv: JsValue = v.into();
-                js_sys::Date::new(&jsv).into()
+                let ms = v as f64 * MS_IN_DAY;
+                js_sys::Date::new(&ms.into()).into()
             }
-            AnyValue::Datetime(v, _, _) => {
-                let jsv: JsValue = v.into();
-                let dt = js_sys::Date::new(&jsv);
-                dt.into()
+            AnyValue::Datetime(v, tu, _) => {
+                let ms = match tu {
+                    TimeUnit::Nanoseconds => v as f64 / 1_000_000.0,
+                    TimeUnit::Microseconds => v as f64 / 1_000.0,
+                    TimeUnit::Milliseconds => v as f64,
+                };
+                js_sys::Date::new(&ms.into()).into()
             }
+            AnyValue::Duration(v, _) => v.into(),
             AnyValue::Time(v) => v.into(),
-            _ => todo!(),
+            AnyValue::Binary(v) => js_sys::Uint8Array::from(v).into(),
+            AnyValue::BinaryOwned(v) => js_sys::Uint8Array::from(v.as_slice()).into(),
+            AnyValue::Decimal(v, scale) => (v as f64 / 10f64.powi(scale as i32)).into(),
+            AnyValue::List(s) | AnyValue::Array(s, _) => s
+                .rechunk()
+                .iter()
+                .map(|av| JsValue::from(Wrap(av)))
+                .collect::<js_sys::Array>()
+                .into(),
+            AnyValue::Struct(_, _, fields) => {
+                let values = av.0._iter_struct_av();
+                struct_to_object(fields, values)
+            }
+            AnyValue::StructOwned(payload) => {
+                let (values, fields) = *payload;
+                struct_to_object(&fields, values.into_iter())
+            }
         }
     }
 }
 
-pub fn df_to_struct(_df: &DataFrame) -> JsResult<js_sys::Array> {
-    todo!()
+fn struct_to_object<'a>(fields: &[Field], values: impl Iterator<Item = AnyValue<'a>>) -> JsValue {
+    let obj = js_sys::Object::new();
+    for (field, av) in fields.iter().zip(values) {
+        let key = JsValue::from_str(field.name());
+        js_sys::Reflect::set(&obj, &key, &Wrap(av).into()).unwrap();
+    }
+    obj.into()
+}
+
+/// Convert the rows of a DataFrame to an array of objects keyed by column name.
+pub fn df_to_struct(df: &DataFrame) -> JsResult<js_sys::Array> {
+    let height = df.height() as u32;
+    let rows = js_sys::Array::new_with_length(height);
+    let keys: Vec<JsValue> = df
+        .get_columns()
+        .iter()
+        .map(|s| JsValue::from_str(s.name()))
+        .collect();
+
+    for idx in 0..height {
+        let obj = js_sys::Object::new();
+
+        for (key, col) in keys.iter().zip(df.get_columns()) {
+            let av = col.get(idx as usize).map_err(JsPolarsErr::from)?;
+            js_sys::Reflect::set(&obj, key, &Wrap(av).into())?;
+        }
+        rows.set(idx, obj.into());
+    }
+    Ok(rows)
 }
@@ -1,9 +1,14 @@
 use super::{error::JsPolarsErr, series::*, JsResult};
 use crate::conversion::*;
-use crate::datatypes::JsDataType;
+use crate::datatypes::{schema_to_js, JsDataType};
+use crate::lazy::dataframe::JsLazyFrame;
+use crate::utils::{
+    char_to_ascii_byte, js_array_to_strings, js_object_to_schema, str_to_quantile_interpolation,
+};
 use polars::prelude::*;
 
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 #[wasm_bindgen(js_name=DataFrame)]
 pub struct JsDataFrame {
@@ -14,6 +19,16 @@ impl JsDataFrame {
     pub(crate) fn new(df: DataFrame) -> Self {
         JsDataFrame { df }
     }
+
+    fn write_json_format(&mut self, format: JsonFormat) -> JsResult<String> {
+        let mut buf: Vec<u8> = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(format)
+            .finish(&mut self.df)
+            .map_err(JsPolarsErr::from)?;
+        let s = String::from_utf8(buf).map_err(|e| JsPolarsErr::Other(e.to_string()))?;
+        Ok(s)
+    }
 }
 impl From<DataFrame> for JsDataFrame {
     fn from(df: DataFrame) -> Self {
@@ -35,6 +50,25 @@ extern "C" {
     pub type DataFrameArray;
 }
 
+crate::extern_struct!(ExternDataFrame, JsDataFrame);
+
+thread_local! {
+    /// Prototype of the exported `DataFrame` class, taken from a throwaway instance.
+    static DATAFRAME_PROTOTYPE: js_sys::Object =
+        js_sys::Object::get_prototype_of(&JsDataFrame::from(DataFrame::default()).into());
+}
+
+/// Clone the frame out of a JS `DataFrame` object, if `jsv` is one.
+pub(crate) fn js_value_to_dataframe(jsv: &JsValue) -> Option<DataFrame> {
+    use crate::conversion::extern_struct::{is_live_instance, RefRustStruct};
+    if !DATAFRAME_PROTOTYPE.with(|proto| is_live_instance(jsv, proto)) {
+        return None;
+    }
+    let df: &ExternDataFrame = jsv.unchecked_ref();
+    let df = df.ref_rust().df.clone();
+    Some(df)
+}
+
 #[wasm_bindgen(js_class=DataFrame)]
 impl JsDataFrame {
     #[wasm_bindgen(constructor)]
@@ -54,6 +88,105 @@ impl JsDataFrame {
         Ok(JsDataFrame::from(df))
     }
 
+    /// Create a DataFrame from an array of objects, the inverse of `to_records`.
+    /// Columns are ordered by the first appearance of their key, and keys that are missing
+    /// from a record become null values.
+    /// @param records - Array of objects, one per row.
+    /// @param schema - Object of column names to DataTypes, overriding the inferred dtypes.
+    /// @param inferSchemaLength - Number of rows used to infer the dtypes, `null` uses all rows.
+    /// @example
+    /// ```js
+    /// > DataFrame.from_records([{ a: 1, b: "x" }, { a: 2 }], { a: DataType.Int32() }, 100)
+    /// ```
+    pub fn from_records(
+        records: &js_sys::Array,
+        schema: JsValue,
+        infer_schema_length: Option<usize>,
+    ) -> JsResult<JsDataFrame> {
+        let schema = js_value_to_schema_overrides(&schema)?;
+        let height = records.length() as usize;
+        let mut columns: PlIndexMap<String, Vec<AnyValue>> = PlIndexMap::default();
+        for (idx, record) in records.iter().enumerate() {
+            if !record.is_object() {
+                let msg = format!("record {} is not an object", idx);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
+            for entry in js_sys::Object::entries(record.unchecked_ref()).iter() {
+                let entry: js_sys::Array = entry.unchecked_into();
+                let name = entry.get(0).as_string().unwrap_or_default();
+                let av = AnyValue::try_from_js(entry.get(1))?;
+                let values = columns
+                    .entry(name)
+                    .or_insert_with(|| Vec::with_capacity(height));
+                values.resize(idx, AnyValue::Null);
+                values.push(av);
+            }
+        }
+        for (name, _) in schema.iter() {
+            columns.entry(name.to_string()).or_default();
+        }
+
+        let mut cols = Vec::with_capacity(columns.len());
+        for (name, mut values) in columns {
+            values.resize(height, AnyValue::Null);
+            let dtype = match (schema.get(name.as_str()), infer_schema_length) {
+                (Some(dtype), _) => Some(dtype.clone()),
+                (None, Some(n)) => {
+                    let n = n.min(height);
+                    let s = any_values_to_series(&name, &values[..n], None, false)?;
+                    // only nulls in the first rows, infer from all rows instead
+                    match s.dtype() {
+                        DataType::Null => None,
+                        dtype => Some(dtype.clone()),
+                    }
+                }
+                (None, None) => None,
+            };
+            let s = any_values_to_series(&name, &values, dtype.as_ref(), true)?;
+            cols.push(s);
+        }
+        let df = DataFrame::new(cols).map_err(JsPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    /// Create a DataFrame from an object of column names to values.
+    /// @param columns - Object of column names to arrays, TypedArrays or Series.
+    /// @param schema - Object of column names to DataTypes, overriding the inferred dtypes.
+    /// @example
+    /// ```js
+    /// > DataFrame.from_columns({ a: [1, 2], b: new Int32Array([3, 4]), c: ["x", null] })
+    /// ```
+    pub fn from_columns(columns: &js_sys::Object, schema: JsValue) -> JsResult<JsDataFrame> {
+        let schema = js_value_to_schema_overrides(&schema)?;
+        let entries = js_sys::Object::entries(columns);
+        let mut cols = Vec::with_capacity(entries.length() as usize);
+        for entry in entries.iter() {
+            let entry: js_sys::Array = entry.unchecked_into();
+            let name = entry.get(0).as_string().unwrap_or_default();
+            let values = entry.get(1);
+            let dtype = schema.get(name.as_str());
+            let s = if js_sys::Array::is_array(&values) {
+                js_array_to_series(&name, values.unchecked_ref(), dtype, true)?
+            } else {
+                let s = match AnyValue::try_from_js(values)? {
+                    AnyValue::List(s) => s,
+                    _ => {
+                        let msg = format!("values of column '{}' are not an array", name);
+                        return Err(JsPolarsErr::Other(msg).into());
+                    }
+                };
+                let s = match dtype {
+                    Some(dtype) => s.strict_cast(dtype).map_err(JsPolarsErr::from)?,
+                    None => s,
+                };
+                s.with_name(name.as_str().into())
+            };
+            cols.push(s);
+        }
+        let df = DataFrame::new(cols).map_err(JsPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     /// Format `DataFrame` as String
     pub fn as_str(&self) -> String {
         format!("{:?}", self.df)
@@ -117,13 +250,14 @@ impl JsDataFrame {
         self.df
             .dtypes()
             .iter()
-            .map(|arrow_dtype| {
-                let dt: JsDataType = arrow_dtype.into();
-                let jsv: JsValue = dt.to_string().into();
-                jsv
-            })
+            .map(|dt| JsValue::from(JsDataType::from(dt)))
             .collect()
     }
+    /// Get an ordered `Map` of column names to their `DataType`.
+    #[wasm_bindgen(getter)]
+    pub fn schema(&self) -> js_sys::Map {
+        schema_to_js(&self.df.schema())
+    }
     pub fn n_chunks(&self) -> JsResult<usize> {
         let n = self.df.n_chunks();
         Ok(n)
@@ -170,13 +304,27 @@ impl JsDataFrame {
         let s = self.df.drop_in_place(name).map_err(JsPolarsErr::from)?;
         Ok(JsSeries { series: s })
     }
-    pub fn drop_nulls(&self, _subset: Option<js_sys::Array>) -> JsResult<JsDataFrame> {
-        todo!()
+    /// Drop the rows that contain null values.
+    /// @param subset - Only consider these columns, defaults to all columns.
+    pub fn drop_nulls(&self, subset: Option<js_sys::Array>) -> JsResult<JsDataFrame> {
+        let subset = subset.as_ref().map(js_array_to_strings).transpose()?;
+        let df = self
+            .df
+            .drop_nulls(subset.as_deref())
+            .map_err(JsPolarsErr::from)?;
+        Ok(df.into())
     }
     pub fn drop(&self, name: &str) -> JsResult<JsDataFrame> {
         let df = self.df.drop(name).map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
+    /// Decompose struct columns into a column per field.
+    /// @param columns - Names of the struct columns to unnest.
+    pub fn unnest(&self, columns: &js_sys::Array) -> JsResult<JsDataFrame> {
+        let columns = js_array_to_strings(columns)?;
+        let df = self.df.unnest(columns).map_err(JsPolarsErr::from)?;
+        Ok(JsDataFrame::new(df))
+    }
     pub fn select_at_idx(&self, idx: usize) -> Option<JsSeries> {
         self.df.select_at_idx(idx).map(|s| JsSeries::new(s.clone()))
     }
@@ -315,24 +463,146 @@ impl JsDataFrame {
         let keep = match keep {
             "first" => UniqueKeepStrategy::First,
             "last" => UniqueKeepStrategy::Last,
-            s => panic!("keep strategy {} is not supported", s),
+            s => {
+                let msg = format!("keep strategy {} is not supported", s);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
         };
-        let subset: Option<Vec<String>> = subset.map(|v| {
-            let s: Vec<String> = v.iter().map(|item| item.as_string().unwrap()).collect();
-            s
-        });
-
-        let subset = subset.as_ref().map(|v| v.as_ref());
+        let subset = subset.as_ref().map(js_array_to_strings).transpose()?;
+        let subset = subset.as_deref();
 
         let df = match maintain_order {
             true => self.df.unique_stable(subset, keep, None),
             false => self.df.unique::<(), ()>(subset, keep, None),
         }
-        .map_err(JsPolarsErr::from)
-        .unwrap();
+        .map_err(JsPolarsErr::from)?;
         Ok(JsDataFrame::new(df))
     }
 
+    /// Start a group by operation.
+    /// @param by - Column name(s) to group by.
+    /// @param maintainOrder - Keep the groups in the order they first appear.
+    #[wasm_bindgen(js_name = "__group_by", skip_typescript)]
+    pub fn group_by(&self, by: &js_sys::Array, maintain_order: bool) -> JsResult<JsGroupBy> {
+        let by = js_array_to_strings(by)?;
+        Ok(JsGroupBy {
+            df: self.df.clone(),
+            by,
+            maintain_order,
+        })
+    }
+
+    /// Write the `DataFrame` to Apache Parquet bytes.
+    /// @param compression - One of `uncompressed`, `snappy`, `gzip`, `lzo`, `brotli`, `lz4`, `zstd`.
+    /// @param compressionLevel - Level for `gzip`, `brotli` and `zstd`, default level when omitted.
+    /// @param statistics - Write min/max/null count statistics for every column chunk.
+    /// @param rowGroupSize - Number of rows per row group, defaults to the whole frame.
+    pub fn write_parquet(
+        &mut self,
+        compression: &str,
+        compression_level: Option<i32>,
+        statistics: bool,
+        row_group_size: Option<usize>,
+    ) -> JsResult<Vec<u8>> {
+        let compression = crate::io::parse_parquet_compression(compression, compression_level)?;
+        let statistics = if statistics {
+            StatisticsOptions::default()
+        } else {
+            StatisticsOptions::empty()
+        };
+        let mut buf: Vec<u8> = Vec::new();
+        ParquetWriter::new(&mut buf)
+            .with_compression(compression)
+            .with_statistics(statistics)
+            .with_row_group_size(row_group_size)
+            .finish(&mut self.df)
+            .map_err(JsPolarsErr::from)?;
+        Ok(buf)
+    }
+
+    /// Write the `DataFrame` to Arrow IPC file (Feather v2) bytes.
+    /// @param compression - One of `uncompressed`, `lz4`, `zstd`.
+    pub fn write_ipc(&mut self, compression: &str) -> JsResult<Vec<u8>> {
+        let compression = crate::io::parse_ipc_compression(compression)?;
+        let mut buf: Vec<u8> = Vec::new();
+        IpcWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(&mut self.df)
+            .map_err(JsPolarsErr::from)?;
+        Ok(buf)
+    }
+
+    /// Write the `DataFrame` to Arrow IPC stream bytes.
+    /// @param compression - One of `uncompressed`, `lz4`, `zstd`.
+    pub fn write_ipc_stream(&mut self, compression: &str) -> JsResult<Vec<u8>> {
+        let compression = crate::io::parse_ipc_compression(compression)?;
+        let mut buf: Vec<u8> = Vec::new();
+        IpcStreamWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(&mut self.df)
+            .map_err(JsPolarsErr::from)?;
+        Ok(buf)
+    }
+
+    /// Serialize the `DataFrame` to a JSON array of row objects.
+    pub fn write_json(&mut self) -> JsResult<String> {
+        self.write_json_format(JsonFormat::Json)
+    }
+
+    /// Serialize the `DataFrame` to newline delimited JSON, one row object per line.
+    pub fn write_ndjson(&mut self) -> JsResult<String> {
+        self.write_json_format(JsonFormat::JsonLines)
+    }
+
+    /// Write the `DataFrame` to a CSV string.
+    /// @param separator - Single byte character used to separate fields.
+    /// @param quoteChar - Single byte character used for quoting.
+    /// @param quoteStyle - One of `necessary`, `always`, `non_numeric`, `never`.
+    /// @param includeHeader - Write the column names as the first line.
+    /// @param nullValue - String written for null values.
+    /// @param floatPrecision - Number of decimal places to write for floats.
+    /// @param dateFormat - A `chrono` format string for `Date` columns.
+    /// @param datetimeFormat - A `chrono` format string for `Datetime` columns.
+    /// @param timeFormat - A `chrono` format string for `Time` columns.
+    pub fn write_csv(
+        &mut self,
+        separator: char,
+        quote_char: char,
+        quote_style: &str,
+        include_header: bool,
+        null_value: String,
+        float_precision: Option<usize>,
+        date_format: Option<String>,
+        datetime_format: Option<String>,
+        time_format: Option<String>,
+    ) -> JsResult<String> {
+        let quote_style = match quote_style {
+            "necessary" => QuoteStyle::Necessary,
+            "always" => QuoteStyle::Always,
+            "non_numeric" => QuoteStyle::NonNumeric,
+            "never" => QuoteStyle::Never,
+            e => {
+                let msg = format!("quote style {} not implemented.", e);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
+        };
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_separator(char_to_ascii_byte(separator, "separator")?)
+            .with_quote_char(char_to_ascii_byte(quote_char, "quoteChar")?)
+            .with_quote_style(quote_style)
+            .include_header(include_header)
+            .with_null_value(null_value)
+            .with_float_precision(float_precision)
+            .with_date_format(date_format)
+            .with_datetime_format(datetime_format)
+            .with_time_format(time_format)
+            .finish(&mut self.df)
+            .map_err(JsPolarsErr::from)?;
+        let s = String::from_utf8(buf).map_err(|e| JsPolarsErr::Other(e.to_string()))?;
+        Ok(s)
+    }
+
     pub fn lazy(&self) -> crate::lazy::dataframe::JsLazyFrame {
         self.df.clone().lazy().into()
     }
@@ -343,20 +613,7 @@ impl JsDataFrame {
     }
 
     pub fn to_records(&self) -> JsResult<js_sys::Array> {
-        let height = self.df.height() as u32;
-        let rows = js_sys::Array::new_with_length(height);
-
-        for idx in 0..height {
-            let obj = js_sys::Object::new();
-
-            for col in self.df.get_columns() {
-                let key: JsValue = col.name().to_string().into();
-                let val: JsValue = Wrap(col.get(idx as usize).unwrap()).into();
-                js_sys::Reflect::set(&obj, &key, &val)?;
-            }
-            rows.set(idx, obj.into());
-        }
-        Ok(rows)
+        df_to_struct(&self.df)
     }
 
     pub fn handle_records(&self, f: &js_sys::Function) -> JsResult<()> {
@@ -378,3 +635,109 @@ impl JsDataFrame {
         Ok(())
     }
 }
+
+/// A pending group by on a `DataFrame`. The aggregations only plan the query and
+/// return a `LazyFrame`; the JS `GroupBy` collects it in the worker.
+#[wasm_bindgen(js_name=GroupBy)]
+pub struct JsGroupBy {
+    df: DataFrame,
+    by: Vec<String>,
+    maintain_order: bool,
+}
+
+impl JsGroupBy {
+    fn lazy_group_by(&self) -> LazyGroupBy {
+        let by: Vec<Expr> = self.by.iter().map(|name| col(name.as_str())).collect();
+        let ldf = self.df.clone().lazy();
+        if self.maintain_order {
+            ldf.group_by_stable(by)
+        } else {
+            ldf.group_by(by)
+        }
+    }
+
+    fn agg_all(&self, f: impl Fn(Expr) -> Expr) -> JsLazyFrame {
+        let aggs = f(col("*").exclude(&self.by));
+        self.lazy_group_by().agg([aggs]).into()
+    }
+}
+
+#[wasm_bindgen(js_class=GroupBy)]
+impl JsGroupBy {
+    /// Compute the given aggregations for every group.
+    /// @param aggs - Aggregation expressions, one output column per expression.
+    pub fn agg(&self, aggs: &js_sys::Array) -> JsResult<JsLazyFrame> {
+        let aggs = crate::lazy::dataframe::js_exprs_to_exprs(aggs)?;
+        Ok(self.lazy_group_by().agg(aggs).into())
+    }
+
+    /// Return the first `n` rows of each group.
+    pub fn head(&self, n: Option<usize>) -> JsLazyFrame {
+        self.lazy_group_by().head(n).into()
+    }
+
+    /// Return the last `n` rows of each group.
+    pub fn tail(&self, n: Option<usize>) -> JsLazyFrame {
+        self.lazy_group_by().tail(n).into()
+    }
+
+    /// Aggregate the groups to their sum.
+    pub fn sum(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.sum())
+    }
+
+    /// Aggregate the groups to their mean.
+    pub fn mean(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.mean())
+    }
+
+    /// Aggregate the groups to their minimal value.
+    pub fn min(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.min())
+    }
+
+    /// Aggregate the groups to their maximal value.
+    pub fn max(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.max())
+    }
+
+    /// Aggregate the groups to their first value.
+    pub fn first(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.first())
+    }
+
+    /// Aggregate the groups to their last value.
+    pub fn last(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.last())
+    }
+
+    /// Count the number of unique values per group.
+    pub fn n_unique(&self) -> JsLazyFrame {
+        self.agg_all(|e| e.n_unique())
+    }
+
+    /// Count the number of rows per group, in a column named `count`.
+    pub fn count(&self) -> JsLazyFrame {
+        self.lazy_group_by().agg([len().alias("count")]).into()
+    }
+
+    /// Aggregate the groups to the given quantile.
+    /// @param quantile - Quantile between 0.0 and 1.0.
+    /// @param interpolation - One of `nearest`, `lower`, `higher`, `midpoint` or `linear`.
+    pub fn quantile(
+        &self,
+        quantile: f64,
+        interpolation: &str,
+    ) -> JsResult<JsLazyFrame> {
+        let interpolation = str_to_quantile_interpolation(interpolation)?;
+        Ok(self.agg_all(|e| e.quantile(lit(quantile), interpolation)))
+    }
+}
+
+fn js_value_to_schema_overrides(schema: &JsValue) -> JsResult<Schema> {
+    if schema.is_null() || schema.is_undefined() {
+        Ok(Schema::default())
+    } else {
+        js_object_to_schema(schema)
+    }
+}
@@ -1,81 +1,433 @@
-use polars::prelude::DataType;
-
-#[repr(u32)]
-pub enum JsDataType {
-    Int8,
-    Int16,
-    Int32,
-    Int64,
-    UInt8,
-    UInt16,
-    UInt32,
-    UInt64,
-    Float32,
-    Float64,
-    Bool,
-    String,
-    List,
-    Date,
-    Datetime,
-    Time,
-    // Object,
-    Categorical,
+use polars::prelude::*;
+use std::sync::Mutex;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::{js_value_to_polarstype, str_to_categorical_ordering, str_to_timeunit};
+use crate::JsResult;
+
+/// A polars data type together with its parameters: time unit and time zone of
+/// temporal types, the inner type of lists and arrays, the fields of structs and
+/// the categories of enums.
+#[wasm_bindgen(js_name = DataType)]
+#[derive(Clone)]
+pub struct JsDataType {
+    pub(crate) dtype: DataType,
+}
+
+impl From<DataType> for JsDataType {
+    fn from(dtype: DataType) -> Self {
+        JsDataType { dtype }
+    }
+}
+
+impl From<&DataType> for JsDataType {
+    fn from(dtype: &DataType) -> Self {
+        JsDataType {
+            dtype: dtype.clone(),
+        }
+    }
+}
+
+pub fn timeunit_to_str(tu: &TimeUnit) -> &'static str {
+    match tu {
+        TimeUnit::Nanoseconds => "ns",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Milliseconds => "ms",
+    }
+}
+
+fn categorical_ordering_to_str(ordering: &CategoricalOrdering) -> &'static str {
+    match ordering {
+        CategoricalOrdering::Physical => "physical",
+        CategoricalOrdering::Lexical => "lexical",
+    }
+}
+
+fn set(obj: &js_sys::Object, key: &str, value: JsValue) {
+    js_sys::Reflect::set(obj, &JsValue::from_str(key), &value).unwrap();
 }
 
 impl JsDataType {
-    pub fn to_string(self) -> String {
-        match self {
-            JsDataType::Int8 => "Int8",
-            JsDataType::Int16 => "Int16",
-            JsDataType::Int32 => "Int32",
-            JsDataType::Int64 => "Int64",
-            JsDataType::UInt8 => "UInt8",
-            JsDataType::UInt16 => "UInt16",
-            JsDataType::UInt32 => "UInt32",
-            JsDataType::UInt64 => "UInt64",
-            JsDataType::Float32 => "Float32",
-            JsDataType::Float64 => "Float64",
-            JsDataType::Bool => "Bool",
-            JsDataType::String => "String",
-            JsDataType::List => "List",
-            JsDataType::Date => "Date",
-            JsDataType::Datetime => "Datetime",
-            JsDataType::Time => "Time",
-            // JsDataType::Object => "Object",
-            JsDataType::Categorical => "Categorical",
+    /// Name of the data type without its parameters.
+    pub fn name(dtype: &DataType) -> &'static str {
+        match dtype {
+            DataType::Boolean => "Bool",
+            DataType::UInt8 => "UInt8",
+            DataType::UInt16 => "UInt16",
+            DataType::UInt32 => "UInt32",
+            DataType::UInt64 => "UInt64",
+            DataType::Int8 => "Int8",
+            DataType::Int16 => "Int16",
+            DataType::Int32 => "Int32",
+            DataType::Int64 => "Int64",
+            DataType::Float32 => "Float32",
+            DataType::Float64 => "Float64",
+            DataType::Decimal(_, _) => "Decimal",
+            DataType::String => "String",
+            DataType::Binary | DataType::BinaryOffset => "Binary",
+            DataType::Date => "Date",
+            DataType::Datetime(_, _) => "Datetime",
+            DataType::Duration(_) => "Duration",
+            DataType::Time => "Time",
+            DataType::Array(_, _) => "Array",
+            DataType::List(_) => "List",
+            DataType::Null => "Null",
+            DataType::Categorical(_, _) => "Categorical",
+            DataType::Enum(_, _) => "Enum",
+            DataType::Struct(_) => "Struct",
+            DataType::Unknown(_) => "Unknown",
         }
-        .to_owned()
+    }
+
+    fn dtype_categories(dtype: &DataType) -> Option<js_sys::Array> {
+        match dtype {
+            DataType::Categorical(Some(rev_map), _) | DataType::Enum(Some(rev_map), _) => Some(
+                rev_map
+                    .get_categories()
+                    .values_iter()
+                    .map(JsValue::from_str)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Descriptor object of a data type, as accepted by `cast` and schema options.
+    pub fn descriptor(dtype: &DataType) -> JsValue {
+        let obj = js_sys::Object::new();
+        set(&obj, "type", JsDataType::name(dtype).into());
+        match dtype {
+            DataType::Decimal(precision, scale) => {
+                set(&obj, "precision", (*precision).into());
+                set(&obj, "scale", (*scale).into());
+            }
+            DataType::Datetime(tu, tz) => {
+                set(&obj, "timeUnit", timeunit_to_str(tu).into());
+                set(
+                    &obj,
+                    "timeZone",
+                    tz.as_ref().map(|tz| tz.to_string()).into(),
+                );
+            }
+            DataType::Duration(tu) => set(&obj, "timeUnit", timeunit_to_str(tu).into()),
+            DataType::Array(inner, width) => {
+                set(&obj, "inner", JsDataType::descriptor(inner));
+                set(&obj, "width", (*width).into());
+            }
+            DataType::List(inner) => set(&obj, "inner", JsDataType::descriptor(inner)),
+            DataType::Categorical(_, ordering) | DataType::Enum(_, ordering) => {
+                set(
+                    &obj,
+                    "ordering",
+                    categorical_ordering_to_str(ordering).into(),
+                );
+                if let Some(categories) = JsDataType::dtype_categories(dtype) {
+                    set(&obj, "categories", categories.into());
+                }
+            }
+            DataType::Struct(fields) => {
+                let fields: js_sys::Array = fields
+                    .iter()
+                    .map(|fld| {
+                        let field = js_sys::Object::new();
+                        set(&field, "name", fld.name().as_str().into());
+                        set(&field, "dtype", JsDataType::descriptor(fld.data_type()));
+                        JsValue::from(field)
+                    })
+                    .collect();
+                set(&obj, "fields", fields.into());
+            }
+            _ => {}
+        }
+        obj.into()
     }
 }
 
-impl From<&DataType> for JsDataType {
-    fn from(dt: &DataType) -> Self {
-        use JsDataType::*;
-        match dt {
-            DataType::Int8 => Int8,
-            DataType::Int16 => Int16,
-            DataType::Int32 => Int32,
-            DataType::Int64 => Int64,
-            DataType::UInt8 => UInt8,
-            DataType::UInt16 => UInt16,
-            DataType::UInt32 => UInt32,
-            DataType::UInt64 => UInt64,
-            DataType::Float32 => Float32,
-            DataType::Float64 => Float64,
-            DataType::Boolean => Bool,
-            DataType::String => String,
-            DataType::List(_) => List,
-            DataType::Date => Date,
-            DataType::Datetime(_, _) => Datetime,
-            DataType::Time => Time,
-
-            // DataType::Object(_) => Object,
-            DataType::Categorical(_, _) => Categorical,
-            _ => {
-                panic!("null or unknown not expected here")
+/// Ordered `Map` of column name to `DataType`.
+pub(crate) fn schema_to_js(schema: &Schema) -> js_sys::Map {
+    let map = js_sys::Map::new();
+    for (name, dtype) in schema.iter() {
+        map.set(&JsValue::from_str(name), &JsDataType::from(dtype).into());
+    }
+    map
+}
+
+#[wasm_bindgen(js_class = DataType)]
+impl JsDataType {
+    #[wasm_bindgen(js_name = Bool)]
+    pub fn bool() -> JsDataType {
+        DataType::Boolean.into()
+    }
+    #[wasm_bindgen(js_name = UInt8)]
+    pub fn uint8() -> JsDataType {
+        DataType::UInt8.into()
+    }
+    #[wasm_bindgen(js_name = UInt16)]
+    pub fn uint16() -> JsDataType {
+        DataType::UInt16.into()
+    }
+    #[wasm_bindgen(js_name = UInt32)]
+    pub fn uint32() -> JsDataType {
+        DataType::UInt32.into()
+    }
+    #[wasm_bindgen(js_name = UInt64)]
+    pub fn uint64() -> JsDataType {
+        DataType::UInt64.into()
+    }
+    #[wasm_bindgen(js_name = Int8)]
+    pub fn int8() -> JsDataType {
+        DataType::Int8.into()
+    }
+    #[wasm_bindgen(js_name = Int16)]
+    pub fn int16() -> JsDataType {
+        DataType::Int16.into()
+    }
+    #[wasm_bindgen(js_name = Int32)]
+    pub fn int32() -> JsDataType {
+        DataType::Int32.into()
+    }
+    #[wasm_bindgen(js_name = Int64)]
+    pub fn int64() -> JsDataType {
+        DataType::Int64.into()
+    }
+    #[wasm_bindgen(js_name = Float32)]
+    pub fn float32() -> JsDataType {
+        DataType::Float32.into()
+    }
+    #[wasm_bindgen(js_name = Float64)]
+    pub fn float64() -> JsDataType {
+        DataType::Float64.into()
+    }
+    #[wasm_bindgen(js_name = Decimal)]
+    pub fn decimal(precision: Option<usize>, scale: Option<usize>) -> JsDataType {
+        DataType::Decimal(precision, scale).into()
+    }
+    #[wasm_bindgen(js_name = String)]
+    pub fn string() -> JsDataType {
+        DataType::String.into()
+    }
+    #[wasm_bindgen(js_name = Binary)]
+    pub fn binary() -> JsDataType {
+        DataType::Binary.into()
+    }
+    #[wasm_bindgen(js_name = Date)]
+    pub fn date() -> JsDataType {
+        DataType::Date.into()
+    }
+    #[wasm_bindgen(js_name = Datetime)]
+    pub fn datetime(time_unit: Option<String>, time_zone: Option<String>) -> JsResult<JsDataType> {
+        let tu = match time_unit {
+            Some(tu) => str_to_timeunit(&tu)?,
+            None => TimeUnit::Milliseconds,
+        };
+        Ok(DataType::Datetime(tu, time_zone.map(|tz| tz.into())).into())
+    }
+    #[wasm_bindgen(js_name = Duration)]
+    pub fn duration(time_unit: Option<String>) -> JsResult<JsDataType> {
+        let tu = match time_unit {
+            Some(tu) => str_to_timeunit(&tu)?,
+            None => TimeUnit::Milliseconds,
+        };
+        Ok(DataType::Duration(tu).into())
+    }
+    #[wasm_bindgen(js_name = Time)]
+    pub fn time() -> JsDataType {
+        DataType::Time.into()
+    }
+    #[wasm_bindgen(js_name = Array)]
+    pub fn array(inner: JsValue, width: usize) -> JsResult<JsDataType> {
+        let inner = js_value_to_polarstype(&inner)?;
+        Ok(DataType::Array(Box::new(inner), width).into())
+    }
+    #[wasm_bindgen(js_name = List)]
+    pub fn list(inner: JsValue) -> JsResult<JsDataType> {
+        let inner = js_value_to_polarstype(&inner)?;
+        Ok(DataType::List(Box::new(inner)).into())
+    }
+    #[wasm_bindgen(js_name = Null)]
+    pub fn null() -> JsDataType {
+        DataType::Null.into()
+    }
+    #[wasm_bindgen(js_name = Categorical)]
+    pub fn categorical(ordering: Option<String>) -> JsResult<JsDataType> {
+        let ordering = match ordering {
+            Some(ordering) => str_to_categorical_ordering(&ordering)?,
+            None => CategoricalOrdering::Physical,
+        };
+        Ok(DataType::Categorical(None, ordering).into())
+    }
+    #[wasm_bindgen(js_name = Enum)]
+    pub fn enum_(categories: js_sys::Array) -> JsResult<JsDataType> {
+        let descriptor = js_sys::Object::new();
+        set(&descriptor, "type", "Enum".into());
+        set(&descriptor, "categories", categories.into());
+        Ok(js_value_to_polarstype(&descriptor)?.into())
+    }
+    #[wasm_bindgen(js_name = Struct)]
+    pub fn struct_(fields: JsValue) -> JsResult<JsDataType> {
+        let descriptor = js_sys::Object::new();
+        set(&descriptor, "type", "Struct".into());
+        set(&descriptor, "fields", fields);
+        Ok(js_value_to_polarstype(&descriptor)?.into())
+    }
+    #[wasm_bindgen(js_name = Unknown)]
+    pub fn unknown() -> JsDataType {
+        DataType::Unknown(Default::default()).into()
+    }
+
+    /// Name of the data type without its parameters, e.g. `"Datetime"`.
+    #[wasm_bindgen(getter)]
+    pub fn variant(&self) -> String {
+        JsDataType::name(&self.dtype).to_owned()
+    }
+    #[wasm_bindgen(getter, js_name = timeUnit)]
+    pub fn time_unit(&self) -> Option<String> {
+        match &self.dtype {
+            DataType::Datetime(tu, _) | DataType::Duration(tu) => {
+                Some(timeunit_to_str(tu).to_owned())
             }
+            _ => None,
+        }
+    }
+    #[wasm_bindgen(getter, js_name = timeZone)]
+    pub fn time_zone(&self) -> Option<String> {
+        match &self.dtype {
+            DataType::Datetime(_, tz) => tz.as_ref().map(|tz| tz.to_string()),
+            _ => None,
+        }
+    }
+    #[wasm_bindgen(getter)]
+    pub fn inner(&self) -> Option<JsDataType> {
+        self.dtype.inner_dtype().map(|dt| dt.into())
+    }
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> Option<usize> {
+        match &self.dtype {
+            DataType::Array(_, width) => Some(*width),
+            _ => None,
+        }
+    }
+    #[wasm_bindgen(getter)]
+    pub fn precision(&self) -> Option<usize> {
+        match &self.dtype {
+            DataType::Decimal(precision, _) => *precision,
+            _ => None,
         }
     }
+    #[wasm_bindgen(getter)]
+    pub fn scale(&self) -> Option<usize> {
+        match &self.dtype {
+            DataType::Decimal(_, scale) => *scale,
+            _ => None,
+        }
+    }
+    /// Fields of a struct as an array of `{ name, dtype }` objects.
+    #[wasm_bindgen(getter)]
+    pub fn fields(&self) -> Option<js_sys::Array> {
+        match &self.dtype {
+            DataType::Struct(fields) => Some(
+                fields
+                    .iter()
+                    .map(|fld| {
+                        let field = js_sys::Object::new();
+                        set(&field, "name", fld.name().as_str().into());
+                        set(&field, "dtype", JsDataType::from(fld.data_type()).into());
+                        JsValue::from(field)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+    /// Categories of an enum, or of a categorical once its categories are known.
+    #[wasm_bindgen(getter)]
+    pub fn categories(&self) -> Option<js_sys::Array> {
+        JsDataType::dtype_categories(&self.dtype)
+    }
+    #[wasm_bindgen(getter)]
+    pub fn ordering(&self) -> Option<String> {
+        match &self.dtype {
+            DataType::Categorical(_, ordering) | DataType::Enum(_, ordering) => {
+                Some(categorical_ordering_to_str(ordering).to_owned())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn equals(&self, other: &JsDataType) -> bool {
+        self.dtype == other.dtype
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn repr(&self) -> String {
+        let name = JsDataType::name(&self.dtype);
+        match &self.dtype {
+            DataType::Decimal(precision, scale) => match (precision, scale) {
+                (Some(precision), Some(scale)) => format!("{}({}, {})", name, precision, scale),
+                _ => name.to_owned(),
+            },
+            DataType::Datetime(tu, Some(tz)) => {
+                format!("{}({}, {})", name, timeunit_to_str(tu), tz)
+            }
+            DataType::Datetime(tu, None) | DataType::Duration(tu) => {
+                format!("{}({})", name, timeunit_to_str(tu))
+            }
+            DataType::Array(inner, width) => {
+                format!("{}({}, {})", name, JsDataType::from(&**inner).repr(), width)
+            }
+            DataType::List(inner) => format!("{}({})", name, JsDataType::from(&**inner).repr()),
+            DataType::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|fld| {
+                        format!(
+                            "{}: {}",
+                            fld.name(),
+                            JsDataType::from(fld.data_type()).repr()
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}({{{}}})", name, fields)
+            }
+            _ => name.to_owned(),
+        }
+    }
+
+    /// Descriptor object of the data type. This makes `JSON.stringify` round-trip
+    /// through `cast` and schema options.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> JsValue {
+        JsDataType::descriptor(&self.dtype)
+    }
+}
+
+/// Holds the global string cache while it is enabled from JS.
+static STRING_CACHE: Mutex<Option<StringCacheHolder>> = Mutex::new(None);
+
+/// Enable the global string cache.
+///
+/// Categoricals created while the cache is enabled share the same physical value for
+/// equal strings, so categoricals from separately read frames can be joined or
+/// concatenated.
+#[wasm_bindgen]
+pub fn enable_string_cache() {
+    STRING_CACHE
+        .lock()
+        .unwrap()
+        .get_or_insert_with(StringCacheHolder::hold);
+}
+
+/// Disable the global string cache. The cache is cleared once no operation holds it.
+#[wasm_bindgen]
+pub fn disable_string_cache() {
+    STRING_CACHE.lock().unwrap().take();
+}
+
+/// Check whether the global string cache is enabled.
+#[wasm_bindgen]
+pub fn using_string_cache() -> bool {
+    polars::using_string_cache()
 }
 
 pub enum TypedArrayType {
@@ -1,54 +1,747 @@
 use super::{error::JsPolarsErr, JsResult};
 use crate::dataframe::JsDataFrame;
+use crate::lazy::dataframe::JsLazyFrame;
+use crate::utils::{char_to_ascii_byte, js_array_to_strings, js_object_to_schema};
 use polars::prelude::*;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::io::Cursor;
+use std::num::NonZeroUsize;
 
 use wasm_bindgen::prelude::*;
 
-#[wasm_bindgen]
-pub fn read_csv(
-    buff: &[u8],
+/// Decompress gzip, zlib (deflate) or zstd input, detected by its magic bytes.
+/// Uncompressed input is passed through without copying.
+fn decompress(buff: &[u8]) -> JsResult<Cow<'_, [u8]>> {
+    if buff.len() >= 4 && is_compressed(buff) {
+        let mut out = Vec::new();
+        maybe_decompress_bytes(buff, &mut out).map_err(JsPolarsErr::from)?;
+        Ok(Cow::Owned(out))
+    } else {
+        Ok(Cow::Borrowed(buff))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsNullValues {
+    Single(String),
+    Multiple(Vec<String>),
+    Named(HashMap<String, String>),
+}
+
+impl From<JsNullValues> for NullValues {
+    fn from(v: JsNullValues) -> Self {
+        match v {
+            JsNullValues::Single(s) => NullValues::AllColumnsSingle(s.into()),
+            JsNullValues::Multiple(v) => {
+                NullValues::AllColumns(v.into_iter().map(|s| s.into()).collect())
+            }
+            JsNullValues::Named(m) => NullValues::Named(
+                m.into_iter()
+                    .map(|(name, s)| (name.into(), s.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Options accepted by `read_csv` and `scan_csv`.
+///
+/// `schema` and `schemaOverrides` are not part of this struct, as they are read
+/// from the options object directly to keep the column order of the JS object.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct JsCsvReadOptions {
+    #[serde(alias = "sep", alias = "seperator")]
     separator: char,
-    chunk_size: u32,
+    quote_char: Option<char>,
+    comment_prefix: Option<String>,
+    eol_char: char,
     has_header: bool,
-    n_rows: Option<u32>,
-    skip_rows: u32,
-    rechunk: bool,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    n_rows: Option<usize>,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    chunk_size: Option<usize>,
+    infer_schema_length: Option<usize>,
+    null_values: Option<JsNullValues>,
+    missing_is_null: bool,
+    ignore_errors: bool,
+    truncate_ragged_lines: bool,
+    decimal_comma: bool,
     encoding: String,
-    n_threads: Option<u32>,
-    low_memory: bool,
     parse_dates: bool,
-    skip_rows_after_header: usize,
-) -> JsResult<JsDataFrame> {
-    let n_threads = n_threads.map(|i| i as usize);
-    let n_rows = n_rows.map(|i| i as usize);
-    let skip_rows = skip_rows as usize;
-    let chunk_size = chunk_size as usize;
+    rechunk: bool,
+    low_memory: bool,
+    #[serde(alias = "numThreads")]
+    n_threads: Option<usize>,
+    row_index_name: Option<String>,
+    row_index_offset: u32,
+}
 
-    let encoding = match encoding.as_ref() {
+impl Default for JsCsvReadOptions {
+    fn default() -> Self {
+        Self {
+            separator: ',',
+            quote_char: Some('"'),
+            comment_prefix: None,
+            eol_char: '\n',
+            has_header: true,
+            columns: None,
+            projection: None,
+            n_rows: None,
+            skip_rows: 0,
+            skip_rows_after_header: 0,
+            chunk_size: None,
+            infer_schema_length: Some(100),
+            null_values: None,
+            missing_is_null: true,
+            ignore_errors: false,
+            truncate_ragged_lines: false,
+            decimal_comma: false,
+            encoding: "utf8".into(),
+            parse_dates: false,
+            rechunk: false,
+            low_memory: false,
+            n_threads: None,
+            row_index_name: None,
+            row_index_offset: 0,
+        }
+    }
+}
+
+fn get_schema_option(options: &JsValue, key: &str) -> JsResult<Option<SchemaRef>> {
+    let value = js_sys::Reflect::get(options, &JsValue::from_str(key))?;
+    if value.is_null() || value.is_undefined() {
+        return Ok(None);
+    }
+    Ok(Some(Arc::new(js_object_to_schema(&value)?)))
+}
+
+/// Build the `CsvReadOptions` from a JS options object.
+/// `n_rows` and `infer_schema_length` are returned separately so that scans can
+/// hand them to the query planner instead.
+fn csv_read_options(options: &JsValue) -> JsResult<(CsvReadOptions, Option<usize>, Option<usize>)> {
+    let opts: JsCsvReadOptions = if options.is_null() || options.is_undefined() {
+        JsCsvReadOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options.clone()).map_err(JsPolarsErr::from)?
+    };
+    let schema = get_schema_option(options, "schema")?;
+    let schema_overrides = get_schema_option(options, "schemaOverrides")?;
+
+    let encoding = match opts.encoding.as_ref() {
         "utf8" => CsvEncoding::Utf8,
         "utf8-lossy" => CsvEncoding::LossyUtf8,
         e => return Err(JsPolarsErr::Other(format!("encoding not {} not implemented.", e)).into()),
     };
+    let row_index = opts.row_index_name.map(|name| RowIndex {
+        name: name.into(),
+        offset: opts.row_index_offset,
+    });
+
+    let quote_char = opts
+        .quote_char
+        .map(|c| char_to_ascii_byte(c, "quoteChar"))
+        .transpose()?;
+    let parse_options = CsvParseOptions::default()
+        .with_separator(char_to_ascii_byte(opts.separator, "separator")?)
+        .with_quote_char(quote_char)
+        .with_comment_prefix(opts.comment_prefix.as_deref())
+        .with_eol_char(char_to_ascii_byte(opts.eol_char, "eolChar")?)
+        .with_encoding(encoding)
+        .with_null_values(opts.null_values.map(NullValues::from))
+        .with_missing_is_null(opts.missing_is_null)
+        .with_truncate_ragged_lines(opts.truncate_ragged_lines)
+        .with_decimal_comma(opts.decimal_comma)
+        .with_try_parse_dates(opts.parse_dates);
+
+    let mut read_options = CsvReadOptions::default()
+        .with_parse_options(parse_options)
+        .with_has_header(opts.has_header)
+        .with_columns(
+            opts.columns
+                .map(|cols| cols.into_iter().map(|c| c.into()).collect()),
+        )
+        .with_projection(opts.projection.map(Arc::new))
+        .with_schema(schema)
+        .with_schema_overwrite(schema_overrides)
+        .with_skip_rows(opts.skip_rows)
+        .with_skip_rows_after_header(opts.skip_rows_after_header)
+        .with_ignore_errors(opts.ignore_errors)
+        .with_row_index(row_index)
+        .with_rechunk(opts.rechunk)
+        .with_low_memory(opts.low_memory)
+        .with_n_threads(opts.n_threads);
+    if let Some(chunk_size) = opts.chunk_size {
+        read_options = read_options.with_chunk_size(chunk_size);
+    }
+
+    Ok((read_options, opts.n_rows, opts.infer_schema_length))
+}
+
+#[wasm_bindgen]
+pub fn read_csv(buff: &[u8], options: JsValue) -> JsResult<JsDataFrame> {
+    let (read_options, n_rows, infer_schema_length) = csv_read_options(&options)?;
 
-    let df = CsvReadOptions::default()
-        .with_has_header(has_header)
+    let df = read_options
         .with_n_rows(n_rows)
-        .with_parse_options(
-            CsvParseOptions::default()
-                .with_separator(separator as u8)
-                .with_encoding(encoding)
-                .with_try_parse_dates(parse_dates),
+        .with_infer_schema_length(infer_schema_length)
+        .into_reader_with_file_handle(Cursor::new(decompress(buff)?))
+        .finish()
+        .map_err(JsPolarsErr::from)?;
+
+    Ok(df.into())
+}
+
+/// An in-memory CSV source for the lazy engine.
+///
+/// Projections, predicates and row limits of the query plan are pushed into the
+/// CSV reader so that unused columns are never materialized.
+struct CsvBufferScan {
+    buff: Vec<u8>,
+    options: CsvReadOptions,
+}
+
+impl AnonymousScan for CsvBufferScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        // rows can only be limited in the reader if no predicate is applied after it
+        let n_rows = match scan_opts.predicate {
+            Some(_) => None,
+            None => scan_opts.n_rows,
+        };
+        let mut options = self.options.clone().with_n_rows(n_rows);
+        let mut only_row_index = None;
+        // pushed down columns are names of the (possibly already projected) scan schema
+        if let Some(columns) = scan_opts.with_columns {
+            // the row index is added by the reader, it is not a column of the CSV
+            let row_index = self.options.row_index.as_ref().map(|ri| ri.name.as_str());
+            let file_columns: Arc<[_]> = columns
+                .iter()
+                .filter(|name| Some(name.as_str()) != row_index)
+                .cloned()
+                .collect();
+            options = if file_columns.is_empty() && row_index.is_some() {
+                // read a single column for the row count and drop it afterwards
+                only_row_index = row_index;
+                options
+                    .with_columns(None)
+                    .with_projection(Some(Arc::new(vec![0])))
+            } else {
+                options
+                    .with_columns(Some(file_columns))
+                    .with_projection(None)
+            };
+        }
+        let mut df = options
+            .into_reader_with_file_handle(Cursor::new(self.buff.as_slice()))
+            .finish()?;
+        if let Some(name) = only_row_index {
+            df = df.select([name])?;
+        }
+
+        match scan_opts.predicate {
+            Some(predicate) => {
+                let df = df.lazy().filter(predicate).collect()?;
+                Ok(match scan_opts.n_rows {
+                    Some(n) => df.head(Some(n)),
+                    None => df,
+                })
+            }
+            None => Ok(df),
+        }
+    }
+
+    fn schema(&self, infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        let infer_schema_length = infer_schema_length.unwrap_or(SCAN_INFER_SCHEMA_LENGTH);
+        // only hand the lines needed for inference to the reader
+        let options = &self.options;
+        let parse_options = &options.parse_options;
+        let end = nth_line_end(
+            &self.buff,
+            parse_options.quote_char,
+            parse_options.eol_char,
+            schema_inference_lines(options, infer_schema_length),
         )
-        .with_skip_rows(skip_rows)
-        .with_rechunk(rechunk)
-        .with_chunk_size(chunk_size)
-        .with_low_memory(low_memory)
-        .with_n_threads(n_threads)
-        .with_skip_rows_after_header(skip_rows_after_header)
-        .into_reader_with_file_handle(Cursor::new(buff))
+        .map_or(self.buff.len(), |end| end + 1);
+
+        let df = options
+            .clone()
+            .with_n_rows(Some(infer_schema_length))
+            .with_infer_schema_length(Some(infer_schema_length))
+            .into_reader_with_file_handle(Cursor::new(&self.buff[..end]))
+            .finish()?;
+        Ok(df.schema().into())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// Rows used to infer the schema of a CSV scan if `inferSchemaLength` is `null`.
+const SCAN_INFER_SCHEMA_LENGTH: usize = 10_000;
+
+/// Create a lazy scan over a CSV buffer.
+///
+/// The schema is inferred from the first `inferSchemaLength` rows, at most 10000 rows
+/// if it is `null`, and the same rows are used when the scan is collected.
+/// Inference runs the CSV reader, so this has to be called from the worker.
+#[wasm_bindgen]
+pub fn scan_csv(buff: Vec<u8>, options: JsValue) -> JsResult<JsLazyFrame> {
+    let (options, n_rows, infer_schema_length) = csv_read_options(&options)?;
+    let infer_schema_length = infer_schema_length.unwrap_or(SCAN_INFER_SCHEMA_LENGTH);
+    let options = options.with_infer_schema_length(Some(infer_schema_length));
+
+    // only copy the buffer if it was actually decompressed
+    let decompressed = match decompress(&buff)? {
+        Cow::Owned(decompressed) => Some(decompressed),
+        Cow::Borrowed(_) => None,
+    };
+    let buff = decompressed.unwrap_or(buff);
+    let function = Arc::new(CsvBufferScan { buff, options });
+    let args = ScanArgsAnonymous {
+        infer_schema_length: Some(infer_schema_length),
+        n_rows,
+        name: "CSV BUFFER SCAN",
+        ..Default::default()
+    };
+    let ldf = LazyFrame::anonymous_scan(function, args).map_err(JsPolarsErr::from)?;
+    Ok(ldf.into())
+}
+
+/// Position of the last line terminator that is not inside a quoted field.
+fn last_line_end(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> Option<usize> {
+    let mut in_quotes = false;
+    let mut end = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        if Some(b) == quote_char {
+            in_quotes = !in_quotes;
+        } else if b == eol_char && !in_quotes {
+            end = Some(i);
+        }
+    }
+    end
+}
+
+/// Position of the `n`-th line terminator that is not inside a quoted field.
+fn nth_line_end(bytes: &[u8], quote_char: Option<u8>, eol_char: u8, n: usize) -> Option<usize> {
+    let mut in_quotes = false;
+    let mut seen = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if Some(b) == quote_char {
+            in_quotes = !in_quotes;
+        } else if b == eol_char && !in_quotes {
+            seen += 1;
+            if seen == n {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Number of lines up to and including the last row used for schema inference.
+fn schema_inference_lines(options: &CsvReadOptions, infer_schema_length: usize) -> usize {
+    options.skip_rows
+        + options.has_header as usize
+        + options.skip_rows_after_header
+        + infer_schema_length
+}
+
+/// Incremental CSV reader that is fed with byte chunks, e.g. from a `ReadableStream`.
+///
+/// Every `push_chunk` parses the complete lines received so far and keeps the trailing
+/// partial line until the next chunk arrives. Lines are buffered until the header, the
+/// skipped rows and the first `inferSchemaLength` rows are complete, or the input ends
+/// if it is `null`; the schema is inferred from them and reused for all following batches.
+/// Compressed input is rejected, as it cannot be decompressed chunk by chunk.
+#[wasm_bindgen(js_name = CsvStreamReader)]
+pub struct JsCsvStreamReader {
+    options: CsvReadOptions,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+    pending: Vec<u8>,
+    checked_compression: bool,
+    df: Option<DataFrame>,
+    n_read: usize,
+    on_batch: Option<js_sys::Function>,
+}
+
+impl JsCsvStreamReader {
+    fn parse_batch(&mut self, bytes: &[u8]) -> JsResult<usize> {
+        let remaining = self.n_rows.map(|n| n.saturating_sub(self.n_read));
+        if remaining == Some(0) {
+            return Ok(0);
+        }
+        if self.options.schema.is_none() {
+            // infer the schema of all columns, so that projections keep working on later batches
+            let df = self
+                .options
+                .clone()
+                .with_columns(None)
+                .with_projection(None)
+                .with_row_index(None)
+                .with_n_rows(self.infer_schema_length)
+                .with_infer_schema_length(self.infer_schema_length)
+                .into_reader_with_file_handle(Cursor::new(bytes))
+                .finish()
+                .map_err(JsPolarsErr::from)?;
+            self.options.schema = Some(df.schema().into());
+        }
+
+        let batch = self
+            .options
+            .clone()
+            .with_n_rows(remaining)
+            .into_reader_with_file_handle(Cursor::new(bytes))
+            .finish()
+            .map_err(JsPolarsErr::from)?;
+        let height = batch.height();
+
+        // the header and skipped rows are only part of the first batch
+        self.options.has_header = false;
+        self.options.skip_rows = 0;
+        self.options.skip_rows_after_header = 0;
+        if let Some(row_index) = self.options.row_index.as_mut() {
+            row_index.offset += height as IdxSize;
+        }
+        self.n_read += height;
+
+        if let Some(f) = &self.on_batch {
+            let this = JsValue::null();
+            f.call1(&this, &JsDataFrame::from(batch.clone()).into())?;
+        }
+        match self.df.as_mut() {
+            Some(df) => {
+                df.vstack_mut(&batch).map_err(JsPolarsErr::from)?;
+            }
+            None => self.df = Some(batch),
+        }
+        Ok(height)
+    }
+}
+
+#[wasm_bindgen(js_class = CsvStreamReader)]
+impl JsCsvStreamReader {
+    /// @param options - The same options as `read_csv`.
+    /// @param onBatch - Called with every parsed batch as a `DataFrame`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        options: JsValue,
+        on_batch: Option<js_sys::Function>,
+    ) -> JsResult<JsCsvStreamReader> {
+        let (options, n_rows, infer_schema_length) = csv_read_options(&options)?;
+        Ok(JsCsvStreamReader {
+            options,
+            n_rows,
+            infer_schema_length,
+            pending: Vec::new(),
+            checked_compression: false,
+            df: None,
+            n_read: 0,
+            on_batch,
+        })
+    }
+
+    /// Append a chunk of bytes and parse all lines completed by it.
+    /// @returns The number of rows parsed from this chunk.
+    pub fn push_chunk(&mut self, chunk: &[u8]) -> JsResult<usize> {
+        self.pending.extend_from_slice(chunk);
+        if !self.checked_compression && self.pending.len() >= 4 {
+            self.checked_compression = true;
+            if is_compressed(&self.pending) {
+                let msg = "compressed CSV streams are not supported, decompress the stream \
+                           first (e.g. with a DecompressionStream) or use read_csv";
+                return Err(JsPolarsErr::Other(msg.into()).into());
+            }
+        }
+
+        let options = &self.options;
+        let parse_options = &options.parse_options;
+        // keep buffering until the header, the skipped rows and all rows used for
+        // schema inference are complete, they only apply to the first batch
+        let infer_schema_length = match options.schema {
+            Some(_) => Some(0),
+            None => self.infer_schema_length,
+        };
+        let required = infer_schema_length.map(|n| schema_inference_lines(options, n));
+        match required {
+            None => return Ok(0),
+            Some(0) => {}
+            Some(n) => {
+                let complete = nth_line_end(
+                    &self.pending,
+                    parse_options.quote_char,
+                    parse_options.eol_char,
+                    n,
+                );
+                if complete.is_none() {
+                    return Ok(0);
+                }
+            }
+        }
+        let end = last_line_end(
+            &self.pending,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        );
+        match end {
+            Some(end) => {
+                let rest = self.pending.split_off(end + 1);
+                let bytes = std::mem::replace(&mut self.pending, rest);
+                self.parse_batch(&bytes)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Parse the remaining bytes and return all rows read as a single `DataFrame`.
+    pub fn finish(&mut self) -> JsResult<JsDataFrame> {
+        let bytes = std::mem::take(&mut self.pending);
+        if !bytes.is_empty() {
+            self.parse_batch(&bytes)?;
+        }
+        let mut df = self.df.take().unwrap_or_default();
+        if self.options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df.into())
+    }
+}
+
+#[wasm_bindgen]
+pub fn read_parquet(
+    buff: &[u8],
+    columns: Option<js_sys::Array>,
+    n_rows: Option<u32>,
+    row_index_name: Option<String>,
+    row_index_offset: u32,
+    parallel: String,
+) -> JsResult<JsDataFrame> {
+    let columns = columns.as_ref().map(js_array_to_strings).transpose()?;
+    let slice = n_rows.map(|n| (0, n as usize));
+    let row_index = row_index_name.map(|name| RowIndex {
+        name: name.into(),
+        offset: row_index_offset,
+    });
+
+    let parallel = match parallel.as_ref() {
+        "auto" => ParallelStrategy::Auto,
+        "columns" => ParallelStrategy::Columns,
+        "row_groups" => ParallelStrategy::RowGroups,
+        "none" => ParallelStrategy::None,
+        e => {
+            let msg = format!("parallel strategy {} not implemented.", e);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+
+    let df = ParquetReader::new(Cursor::new(decompress(buff)?))
+        .with_columns(columns)
+        .with_slice(slice)
+        .with_row_index(row_index)
+        .read_parallel(parallel)
         .finish()
         .map_err(JsPolarsErr::from)?;
 
     Ok(df.into())
 }
+
+pub(crate) fn parse_parquet_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> JsResult<ParquetCompression> {
+    let compression = match compression {
+        "uncompressed" => ParquetCompression::Uncompressed,
+        "snappy" => ParquetCompression::Snappy,
+        "gzip" => ParquetCompression::Gzip(
+            compression_level
+                .map(|lvl| {
+                    GzipLevel::try_new(compression_level_to(lvl)?).map_err(JsPolarsErr::from)
+                })
+                .transpose()?,
+        ),
+        "lzo" => ParquetCompression::Lzo,
+        "brotli" => ParquetCompression::Brotli(
+            compression_level
+                .map(|lvl| {
+                    BrotliLevel::try_new(compression_level_to(lvl)?).map_err(JsPolarsErr::from)
+                })
+                .transpose()?,
+        ),
+        "lz4" => ParquetCompression::Lz4Raw,
+        "zstd" => ParquetCompression::Zstd(
+            compression_level
+                .map(ZstdLevel::try_new)
+                .transpose()
+                .map_err(JsPolarsErr::from)?,
+        ),
+        e => {
+            let msg = format!("compression {} not implemented.", e);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(compression)
+}
+
+/// Check that a compression level fits the integer type of the codec.
+fn compression_level_to<T: TryFrom<i32>>(level: i32) -> Result<T, JsPolarsErr> {
+    T::try_from(level)
+        .map_err(|_| JsPolarsErr::Other(format!("invalid compression level {}", level)))
+}
+
+/// Options shared by the IPC file and stream readers.
+struct IpcReadOptions {
+    columns: Option<Vec<String>>,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+}
+
+impl IpcReadOptions {
+    fn new(
+        columns: Option<js_sys::Array>,
+        n_rows: Option<u32>,
+        row_index_name: Option<String>,
+        row_index_offset: u32,
+    ) -> JsResult<Self> {
+        let columns = columns.as_ref().map(js_array_to_strings).transpose()?;
+        let row_index = row_index_name.map(|name| RowIndex {
+            name: name.into(),
+            offset: row_index_offset,
+        });
+        Ok(IpcReadOptions {
+            columns,
+            n_rows: n_rows.map(|n| n as usize),
+            row_index,
+        })
+    }
+}
+
+#[wasm_bindgen]
+pub fn read_ipc(
+    buff: &[u8],
+    columns: Option<js_sys::Array>,
+    n_rows: Option<u32>,
+    row_index_name: Option<String>,
+    row_index_offset: u32,
+) -> JsResult<JsDataFrame> {
+    let opts = IpcReadOptions::new(columns, n_rows, row_index_name, row_index_offset)?;
+    let df = IpcReader::new(Cursor::new(decompress(buff)?))
+        .with_columns(opts.columns)
+        .with_n_rows(opts.n_rows)
+        .with_row_index(opts.row_index)
+        .finish()
+        .map_err(JsPolarsErr::from)?;
+
+    Ok(df.into())
+}
+
+#[wasm_bindgen]
+pub fn read_ipc_stream(
+    buff: &[u8],
+    columns: Option<js_sys::Array>,
+    n_rows: Option<u32>,
+    row_index_name: Option<String>,
+    row_index_offset: u32,
+) -> JsResult<JsDataFrame> {
+    let opts = IpcReadOptions::new(columns, n_rows, row_index_name, row_index_offset)?;
+    let df = IpcStreamReader::new(Cursor::new(decompress(buff)?))
+        .with_columns(opts.columns)
+        .with_n_rows(opts.n_rows)
+        .with_row_index(opts.row_index)
+        .finish()
+        .map_err(JsPolarsErr::from)?;
+
+    Ok(df.into())
+}
+
+pub(crate) fn parse_ipc_compression(compression: &str) -> JsResult<Option<IpcCompression>> {
+    let compression = match compression {
+        "uncompressed" => None,
+        "lz4" => Some(IpcCompression::LZ4),
+        "zstd" => Some(IpcCompression::ZSTD),
+        e => {
+            let msg = format!("compression {} not implemented.", e);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(compression)
+}
+
+#[wasm_bindgen]
+pub fn read_json(
+    buff: &[u8],
+    infer_schema_length: Option<u32>,
+    schema_overrides: JsValue,
+    batch_size: u32,
+    ignore_errors: bool,
+) -> JsResult<JsDataFrame> {
+    let infer_schema_length = infer_schema_length.and_then(|n| NonZeroUsize::new(n as usize));
+    let batch_size = NonZeroUsize::new(batch_size as usize)
+        .ok_or_else(|| JsPolarsErr::Other("batch_size should be greater than 0".into()))?;
+    let schema_overrides = if schema_overrides.is_null() || schema_overrides.is_undefined() {
+        None
+    } else {
+        Some(js_object_to_schema(&schema_overrides)?)
+    };
+
+    let mut reader = JsonReader::new(Cursor::new(decompress(buff)?))
+        .with_json_format(JsonFormat::Json)
+        .infer_schema_len(infer_schema_length)
+        .with_batch_size(batch_size)
+        .with_ignore_errors(ignore_errors);
+    if let Some(schema) = schema_overrides.as_ref() {
+        reader = reader.with_schema_overwrite(schema);
+    }
+    let df = reader.finish().map_err(JsPolarsErr::from)?;
+
+    Ok(df.into())
+}
+
+#[wasm_bindgen]
+pub fn read_ndjson(
+    buff: &[u8],
+    infer_schema_length: Option<u32>,
+    schema_overrides: JsValue,
+    batch_size: Option<u32>,
+    n_rows: Option<u32>,
+    ignore_errors: bool,
+    low_memory: bool,
+) -> JsResult<JsDataFrame> {
+    let infer_schema_length = infer_schema_length.and_then(|n| NonZeroUsize::new(n as usize));
+    let batch_size = batch_size.and_then(|n| NonZeroUsize::new(n as usize));
+    let schema_overrides = if schema_overrides.is_null() || schema_overrides.is_undefined() {
+        None
+    } else {
+        Some(js_object_to_schema(&schema_overrides)?)
+    };
+
+    let mut reader = JsonLineReader::new(Cursor::new(decompress(buff)?))
+        .infer_schema_len(infer_schema_length)
+        .with_chunk_size(batch_size)
+        .with_n_rows(n_rows.map(|n| n as usize))
+        .with_ignore_errors(ignore_errors)
+        .low_memory(low_memory);
+    if let Some(schema) = schema_overrides.as_ref() {
+        reader = reader.with_schema_overwrite(schema);
+    }
+    let df = reader.finish().map_err(JsPolarsErr::from)?;
+
+    Ok(df.into())
+}
@@ -1,8 +1,14 @@
 use polars::prelude::*;
 use wasm_bindgen::prelude::*;
 
-use super::expr::JsExpr;
-use crate::{dataframe::JsDataFrame, error::JsPolarsErr, JsResult};
+use super::expr::{js_value_to_expr, JsExpr};
+use crate::{
+    dataframe::{js_value_to_dataframe, JsDataFrame},
+    datatypes::schema_to_js,
+    error::JsPolarsErr,
+    utils::js_array_to_strings,
+    JsResult,
+};
 #[wasm_bindgen(js_name = LazyFrame)]
 #[repr(transparent)]
 #[derive(Clone)]
@@ -17,7 +23,7 @@ impl From<LazyFrame> for JsLazyFrame {
 }
 
 impl JsLazyFrame {
-    fn collect_schema(&mut self) -> JsResult<SchemaRef> {
+    fn schema_ref(&mut self) -> JsResult<SchemaRef> {
         self.ldf
             .collect_schema()
             .map_err(|e| JsPolarsErr::from(e).into())
@@ -29,7 +35,7 @@ impl JsLazyFrame {
     #[wasm_bindgen(getter)]
     pub fn columns(&mut self) -> JsResult<JsValue> {
         let cols: Vec<String> = self
-            .collect_schema()?
+            .schema_ref()?
             .iter_names()
             .cloned()
             .map(|s| s.to_string())
@@ -37,6 +43,17 @@ impl JsLazyFrame {
         serde_wasm_bindgen::to_value(&cols).map_err(|e| JsPolarsErr::from(e).into())
     }
 
+    /// Get an ordered `Map` of column names to their `DataType`.
+    #[wasm_bindgen(getter)]
+    pub fn schema(&mut self) -> JsResult<js_sys::Map> {
+        Ok(schema_to_js(&self.schema_ref()?))
+    }
+
+    /// Resolve the schema of the query without executing it.
+    pub fn collect_schema(&mut self) -> JsResult<js_sys::Map> {
+        self.schema()
+    }
+
     /// Cache the result once the execution of the physical plan hits this node.
     pub fn cache(&self) -> JsLazyFrame {
         self.ldf.clone().cache().into()
@@ -177,6 +194,14 @@ impl JsLazyFrame {
         Ok(ldf.with_columns(js_exprs_to_exprs(exprs)?).into())
     }
 
+    /// Decompose struct columns into a column per field.
+    /// @param columns - Names of the struct columns to unnest.
+    pub fn unnest(&self, columns: &js_sys::Array) -> JsResult<JsLazyFrame> {
+        let columns = js_array_to_strings(columns)?;
+        let columns: Vec<&str> = columns.iter().map(|name| name.as_str()).collect();
+        Ok(self.ldf.clone().unnest(columns).into())
+    }
+
     /// Explode lists to long format.
     pub fn explode(&self, _cols: &JsValue) -> JsLazyFrame {
         todo!()
@@ -214,22 +239,143 @@ impl JsLazyFrame {
         let ldf = self.ldf.clone();
         Ok(ldf.select(&exprs).into())
     }
+
+    /// Start a group by operation.
+    /// @param by - Column(s) to group by.
+    /// @param maintainOrder - Make sure that the order of the groups remain consistent.
+    /// This is more expensive than a default group by.
+    /// @example
+    /// ```js
+    /// > lf = pl.DataFrame({
+    /// ...   "a": ["a", "b", "a", "b", "b", "c"],
+    /// ...   "b": [1, 2, 3, 4, 5, 6],
+    /// ... }).lazy()
+    /// > await lf.group_by([pl.col("a")], true).agg([pl.col("b").sum()]).collect()
+    /// shape: (3, 2)
+    /// ┌─────┬─────┐
+    /// │ a   ┆ b   │
+    /// │ --- ┆ --- │
+    /// │ str ┆ i64 │
+    /// ╞═════╪═════╡
+    /// │ a   ┆ 4   │
+    /// ├╌╌╌╌╌┼╌╌╌╌╌┤
+    /// │ b   ┆ 11  │
+    /// ├╌╌╌╌╌┼╌╌╌╌╌┤
+    /// │ c   ┆ 6   │
+    /// └─────┴─────┘
+    /// ```
+    pub fn group_by(&self, by: &js_sys::Array, maintain_order: bool) -> JsResult<JsLazyGroupBy> {
+        let by = js_exprs_to_exprs(by)?;
+        Ok(JsLazyGroupBy {
+            ldf: self.ldf.clone(),
+            by,
+            maintain_order,
+        })
+    }
 }
 
+#[wasm_bindgen(js_name = LazyGroupBy)]
+#[derive(Clone)]
+pub struct JsLazyGroupBy {
+    ldf: LazyFrame,
+    by: Box<[Expr]>,
+    maintain_order: bool,
+}
+
+impl JsLazyGroupBy {
+    fn lazy_group_by(&self, ldf: LazyFrame) -> LazyGroupBy {
+        if self.maintain_order {
+            ldf.group_by_stable(self.by.clone())
+        } else {
+            ldf.group_by(self.by.clone())
+        }
+    }
+}
+
+#[wasm_bindgen(js_class = LazyGroupBy)]
+impl JsLazyGroupBy {
+    /// Describe the aggregation that need to be done on a group.
+    /// @param aggs - Aggregation expressions, one output column per expression.
+    pub fn agg(&self, aggs: &js_sys::Array) -> JsResult<JsLazyFrame> {
+        let aggs = js_exprs_to_exprs(aggs)?;
+        Ok(self.lazy_group_by(self.ldf.clone()).agg(aggs).into())
+    }
+
+    /// Return the first `n` rows of each group.
+    pub fn head(&self, n: Option<usize>) -> JsLazyFrame {
+        self.lazy_group_by(self.ldf.clone()).head(n).into()
+    }
+
+    /// Return the last `n` rows of each group.
+    pub fn tail(&self, n: Option<usize>) -> JsLazyFrame {
+        self.lazy_group_by(self.ldf.clone()).tail(n).into()
+    }
+
+    /// Collect the frame and split it into a `DataFrame` per group, for `apply`.
+    /// This runs the thread pool, so it has to be called from the worker.
+    #[wasm_bindgen(js_name = "__partition_from_worker", skip_typescript)]
+    pub fn partition_from_worker(&self) -> JsResult<js_sys::Array> {
+        const ROW_IDX: &str = "__apply_row_idx";
+        let df = self.ldf.clone().collect().map_err(JsPolarsErr::from)?;
+        let ldf = df.clone().lazy().with_row_index(ROW_IDX, None);
+        let groups = self
+            .lazy_group_by(ldf)
+            .agg([col(ROW_IDX)])
+            .collect()
+            .map_err(JsPolarsErr::from)?;
+        let groups = groups
+            .column(ROW_IDX)
+            .and_then(|s| s.list())
+            .map_err(JsPolarsErr::from)?;
+
+        let out = js_sys::Array::new();
+        for idx in groups.into_iter().flatten() {
+            let idx = idx.idx().map_err(JsPolarsErr::from)?;
+            let group = df.take(idx).map_err(JsPolarsErr::from)?;
+            out.push(&JsDataFrame::from(group).into());
+        }
+        Ok(out)
+    }
+
+    /// Stack the frames returned by the `apply` function.
+    /// This does not use the thread pool, so it can run on the main thread.
+    #[wasm_bindgen(js_name = "__concat_groups", skip_typescript)]
+    pub fn concat_groups(groups: &js_sys::Array) -> JsResult<JsDataFrame> {
+        let mut out: Option<DataFrame> = None;
+        for jsv in groups.iter() {
+            let df = js_value_to_dataframe(&jsv).ok_or_else(|| {
+                let msg = format!("apply must return a DataFrame, got {:?}", jsv);
+                JsPolarsErr::Other(msg)
+            })?;
+            match out.as_mut() {
+                Some(out) => {
+                    out.vstack_mut(&df).map_err(JsPolarsErr::from)?;
+                }
+                None => out = Some(df),
+            }
+        }
+        Ok(out.unwrap_or_default().into())
+    }
+}
+
+/// Convert a JS array of expressions into `Expr`s.
+///
+/// Strings are taken as column names, so that e.g. `Expr.over(["group"])` can
+/// partition by name; the regex and wildcard rules of `col` apply to them.
 pub(crate) fn js_exprs_to_exprs(iter: &js_sys::Array) -> JsResult<Box<[Expr]>> {
-    use wasm_bindgen::convert::FromWasmAbi;
-    use wasm_bindgen::JsCast;
     let iterator = js_sys::try_iter(iter)?.ok_or_else(|| "need to pass iterable JS values!")?;
 
     iterator
         .into_iter()
         .map(|jsv| {
             let jsv = jsv?;
-            let key = JsValue::from_str("ptr");
-            let ptr = js_sys::Reflect::get(&jsv, &key)?;
-            let n: f64 = js_sys::Number::unchecked_from_js(ptr).into();
-            let expr: JsExpr = unsafe { JsExpr::from_abi(n as u32) };
-            Ok(expr.inner)
+            if let Some(name) = jsv.as_string() {
+                return Ok(col(&name));
+            }
+            js_value_to_expr(&jsv).ok_or_else(|| {
+                let msg = format!("expected an Expr or a column name, got {:?}", jsv);
+                JsValue::from(JsPolarsErr::Other(msg))
+            })
         })
         .collect()
 }
@@ -0,0 +1,51 @@
+use polars::lazy::dsl::Expr;
+use polars::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use super::JsExpr;
+use crate::series::JsSeries;
+use crate::utils::str_to_categorical_ordering;
+use crate::JsResult;
+
+#[wasm_bindgen(js_name=CatNameSpace)]
+pub struct JsCatNameSpace {
+    pub(crate) inner: Expr,
+}
+
+#[wasm_bindgen(js_class=CatNameSpace)]
+impl JsCatNameSpace {
+    /// Get the categories as a String column, in the order of their physical values.
+    pub fn get_categories(&self) -> JsExpr {
+        self.inner.clone().cat().get_categories().into()
+    }
+
+    /// Set how the categories are sorted.
+    /// @param ordering - 'physical' to sort by the order the categories were seen,
+    /// 'lexical' to sort by their string values.
+    pub fn set_ordering(&self, ordering: &str) -> JsResult<JsExpr> {
+        let ordering = str_to_categorical_ordering(ordering)?;
+        Ok(self
+            .inner
+            .clone()
+            .cast(DataType::Categorical(None, ordering))
+            .into())
+    }
+}
+
+#[wasm_bindgen(js_name=SeriesCatNameSpace)]
+pub struct JsSeriesCatNameSpace {
+    pub(crate) series: JsSeries,
+}
+
+#[wasm_bindgen(js_class=SeriesCatNameSpace)]
+impl JsSeriesCatNameSpace {
+    pub fn get_categories(&self) -> JsResult<JsSeries> {
+        self.series
+            .apply_expr(|inner| Ok(JsCatNameSpace { inner }.get_categories()))
+    }
+
+    pub fn set_ordering(&self, ordering: &str) -> JsResult<JsSeries> {
+        self.series
+            .apply_expr(|inner| JsCatNameSpace { inner }.set_ordering(ordering))
+    }
+}
@@ -26,3 +26,21 @@ impl From<Expr> for JsExpr {
         JsExpr { inner: s }
     }
 }
+
+thread_local! {
+    /// Prototype of the exported `Expr` class, taken from a throwaway instance.
+    static EXPR_PROTOTYPE: js_sys::Object =
+        js_sys::Object::get_prototype_of(&JsExpr::from(polars::prelude::lit(0)).into());
+}
+
+/// Clone the expression out of a JS `Expr` object, if `jsv` is one.
+pub(crate) fn js_value_to_expr(jsv: &JsValue) -> Option<Expr> {
+    use crate::conversion::extern_struct::{is_live_instance, RefRustStruct};
+    use wasm_bindgen::JsCast;
+    if !EXPR_PROTOTYPE.with(|proto| is_live_instance(jsv, proto)) {
+        return None;
+    }
+    let expr: &ExternExpr = jsv.unchecked_ref();
+    let expr = expr.ref_rust().inner.clone();
+    Some(expr)
+}
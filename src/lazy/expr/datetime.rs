@@ -0,0 +1,232 @@
+use polars::lazy::dsl::Expr;
+use polars::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use super::JsExpr;
+use crate::error::JsPolarsErr;
+use crate::series::JsSeries;
+use crate::utils::{str_to_duration, str_to_timeunit};
+use crate::JsResult;
+
+#[wasm_bindgen(js_name=DateTimeNameSpace)]
+pub struct JsDateTimeNameSpace {
+    pub(crate) inner: Expr,
+}
+
+impl JsDateTimeNameSpace {
+    fn dt(&self) -> DateLikeNameSpace {
+        self.inner.clone().dt()
+    }
+}
+
+#[wasm_bindgen(js_class=DateTimeNameSpace)]
+impl JsDateTimeNameSpace {
+    /// Extract the year.
+    pub fn year(&self) -> JsExpr {
+        self.dt().year().into()
+    }
+
+    /// Extract the month, from 1 to 12.
+    pub fn month(&self) -> JsExpr {
+        self.dt().month().into()
+    }
+
+    /// Extract the day of the month, from 1 to 31.
+    pub fn day(&self) -> JsExpr {
+        self.dt().day().into()
+    }
+
+    /// Extract the ISO weekday, from 1 (monday) to 7 (sunday).
+    pub fn weekday(&self) -> JsExpr {
+        self.dt().weekday().into()
+    }
+
+    pub fn hour(&self) -> JsExpr {
+        self.dt().hour().into()
+    }
+
+    pub fn minute(&self) -> JsExpr {
+        self.dt().minute().into()
+    }
+
+    pub fn second(&self) -> JsExpr {
+        self.dt().second().into()
+    }
+
+    /// Get the time passed since the Unix epoch.
+    /// @param timeUnit - 'd', 's', 'ms', 'us' or 'ns'.
+    pub fn epoch(&self, time_unit: &str) -> JsResult<JsExpr> {
+        let expr = match time_unit {
+            "d" => self
+                .inner
+                .clone()
+                .cast(DataType::Date)
+                .cast(DataType::Int32),
+            "s" => self
+                .dt()
+                .timestamp(TimeUnit::Milliseconds)
+                .floor_div(lit(1000i64)),
+            tu => self.dt().timestamp(str_to_timeunit(tu)?),
+        };
+        Ok(expr.into())
+    }
+
+    /// Truncate the dates to buckets of the given interval, e.g. '1h' or '3d'.
+    pub fn truncate(&self, every: &str) -> JsResult<JsExpr> {
+        str_to_duration(every)?;
+        Ok(self.dt().truncate(lit(every)).into())
+    }
+
+    /// Round the dates to buckets of the given interval, e.g. '1h' or '3d'.
+    pub fn round(&self, every: &str) -> JsResult<JsExpr> {
+        str_to_duration(every)?;
+        Ok(self.dt().round(lit(every)).into())
+    }
+
+    /// Offset the dates by an interval, e.g. '1mo' or '-2d'.
+    /// Calendar intervals take leap years and month lengths into account.
+    pub fn offset_by(&self, by: &str) -> JsResult<JsExpr> {
+        str_to_duration(by)?;
+        Ok(self.dt().offset_by(lit(by)).into())
+    }
+
+    /// Format the dates with a chrono `strftime` format, e.g. '%Y-%m-%d'.
+    pub fn strftime(&self, format: &str) -> JsExpr {
+        self.dt().strftime(format).into()
+    }
+
+    /// Cast the underlying data to another time unit. This may lose precision.
+    /// @param timeUnit - 'ns', 'us' or 'ms'.
+    pub fn cast_time_unit(&self, time_unit: &str) -> JsResult<JsExpr> {
+        let tu = str_to_timeunit(time_unit)?;
+        Ok(self.dt().cast_time_unit(tu).into())
+    }
+
+    /// Set the time zone without converting the wall time.
+    /// @param timeZone - New time zone, `null` makes the datetimes time zone naive.
+    /// @param ambiguous - 'raise', 'earliest', 'latest' or 'null' for ambiguous wall times.
+    /// @param nonExistent - 'raise' or 'null' for wall times that do not exist in the time zone.
+    pub fn replace_time_zone(
+        &self,
+        time_zone: Option<String>,
+        ambiguous: Option<String>,
+        non_existent: Option<String>,
+    ) -> JsResult<JsExpr> {
+        let ambiguous = ambiguous.unwrap_or_else(|| "raise".to_owned());
+        let non_existent = match non_existent.as_deref() {
+            None | Some("raise") => NonExistent::Raise,
+            Some("null") => NonExistent::Null,
+            Some(e) => {
+                let msg = format!("non_existent {} not implemented.", e);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
+        };
+        Ok(self
+            .dt()
+            .replace_time_zone(time_zone.map(|tz| tz.into()), lit(ambiguous), non_existent)
+            .into())
+    }
+
+    /// Convert the datetimes to another time zone.
+    pub fn convert_time_zone(&self, time_zone: String) -> JsExpr {
+        self.dt().convert_time_zone(time_zone.into()).into()
+    }
+
+    /// Extract the date.
+    pub fn date(&self) -> JsExpr {
+        self.dt().date().into()
+    }
+
+    /// Extract the time of day.
+    pub fn time(&self) -> JsExpr {
+        self.dt().time().into()
+    }
+}
+
+#[wasm_bindgen(js_name=SeriesDateTimeNameSpace)]
+pub struct JsSeriesDateTimeNameSpace {
+    pub(crate) series: JsSeries,
+}
+
+impl JsSeriesDateTimeNameSpace {
+    fn apply(&self, f: impl FnOnce(JsDateTimeNameSpace) -> JsResult<JsExpr>) -> JsResult<JsSeries> {
+        self.series
+            .apply_expr(|inner| f(JsDateTimeNameSpace { inner }))
+    }
+}
+
+#[wasm_bindgen(js_class=SeriesDateTimeNameSpace)]
+impl JsSeriesDateTimeNameSpace {
+    pub fn year(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.year()))
+    }
+
+    pub fn month(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.month()))
+    }
+
+    pub fn day(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.day()))
+    }
+
+    pub fn weekday(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.weekday()))
+    }
+
+    pub fn hour(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.hour()))
+    }
+
+    pub fn minute(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.minute()))
+    }
+
+    pub fn second(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.second()))
+    }
+
+    pub fn epoch(&self, time_unit: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.epoch(time_unit))
+    }
+
+    pub fn truncate(&self, every: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.truncate(every))
+    }
+
+    pub fn round(&self, every: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.round(every))
+    }
+
+    pub fn offset_by(&self, by: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.offset_by(by))
+    }
+
+    pub fn strftime(&self, format: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.strftime(format)))
+    }
+
+    pub fn cast_time_unit(&self, time_unit: &str) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.cast_time_unit(time_unit))
+    }
+
+    pub fn replace_time_zone(
+        &self,
+        time_zone: Option<String>,
+        ambiguous: Option<String>,
+        non_existent: Option<String>,
+    ) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.replace_time_zone(time_zone, ambiguous, non_existent))
+    }
+
+    pub fn convert_time_zone(&self, time_zone: String) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.convert_time_zone(time_zone)))
+    }
+
+    pub fn date(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.date()))
+    }
+
+    pub fn time(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.time()))
+    }
+}
@@ -0,0 +1,280 @@
+use polars::lazy::dsl::{self, Expr};
+use polars::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use super::JsExpr;
+use crate::error::JsPolarsErr;
+use crate::lazy::dataframe::js_exprs_to_exprs;
+use crate::lazy::lazy_functions::js_value_to_lit_expr;
+use crate::series::{js_value_to_series, JsSeries};
+use crate::utils::js_array_to_strings;
+use crate::JsResult;
+
+#[wasm_bindgen(js_name=ListNameSpace)]
+pub struct JsListNameSpace {
+    pub(crate) inner: Expr,
+}
+
+impl JsListNameSpace {
+    fn list(&self) -> ListNameSpace {
+        self.inner.clone().list()
+    }
+}
+
+#[wasm_bindgen(js_class=ListNameSpace)]
+impl JsListNameSpace {
+    /// Get the number of values in every sublist.
+    #[wasm_bindgen(js_name = len)]
+    pub fn lengths(&self) -> JsExpr {
+        self.list().len().into()
+    }
+
+    /// Get the value by index in every sublist, negative indices count from the end.
+    /// @param index - Index as a number or an expression.
+    /// @param nullOnOob - Return null instead of raising when the index is out of bounds.
+    pub fn get(&self, index: JsValue, null_on_oob: bool) -> JsResult<JsExpr> {
+        let index = js_value_to_lit_expr(index)?;
+        Ok(self.list().get(index, null_on_oob).into())
+    }
+
+    /// Get the first value of every sublist.
+    pub fn first(&self) -> JsExpr {
+        self.list().first().into()
+    }
+
+    /// Get the last value of every sublist.
+    pub fn last(&self) -> JsExpr {
+        self.list().last().into()
+    }
+
+    /// Slice every sublist.
+    /// @param offset - Start index, negative indices count from the end.
+    /// @param length - Length of the slice, `null` slices to the end of the sublist.
+    pub fn slice(&self, offset: JsValue, length: JsValue) -> JsResult<JsExpr> {
+        let offset = js_value_to_lit_expr(offset)?;
+        let length = js_value_to_lit_expr(length)?;
+        Ok(self.list().slice(offset, length).into())
+    }
+
+    /// Check if the sublists contain the given item.
+    pub fn contains(&self, item: JsValue) -> JsResult<JsExpr> {
+        let item = js_value_to_lit_expr(item)?;
+        Ok(self.list().contains(item).into())
+    }
+
+    pub fn sum(&self) -> JsExpr {
+        self.list().sum().into()
+    }
+
+    pub fn mean(&self) -> JsExpr {
+        self.list().mean().into()
+    }
+
+    pub fn min(&self) -> JsExpr {
+        self.list().min().into()
+    }
+
+    pub fn max(&self) -> JsExpr {
+        self.list().max().into()
+    }
+
+    /// Sort the values of every sublist.
+    pub fn sort(&self, descending: bool, nulls_last: bool) -> JsExpr {
+        let options = SortOptions {
+            descending,
+            nulls_last,
+            ..Default::default()
+        };
+        self.list().sort(options).into()
+    }
+
+    /// Reverse the values of every sublist.
+    pub fn reverse(&self) -> JsExpr {
+        self.list().reverse().into()
+    }
+
+    /// Keep only the unique values of every sublist.
+    /// @param maintainOrder - Keep the order of first occurrence.
+    pub fn unique(&self, maintain_order: bool) -> JsExpr {
+        if maintain_order {
+            self.list().unique_stable().into()
+        } else {
+            self.list().unique().into()
+        }
+    }
+
+    /// Join the string values of every sublist with a separator.
+    /// @param separator - String placed between the values.
+    /// @param ignoreNulls - Skip null values instead of returning null for the sublist.
+    pub fn join(&self, separator: &str, ignore_nulls: bool) -> JsExpr {
+        self.list().join(lit(separator), ignore_nulls).into()
+    }
+
+    /// Concat the sublists with the sublists or values of other columns.
+    /// @param other - Column names or expressions to concat with.
+    pub fn concat(&self, other: &js_sys::Array) -> JsResult<JsExpr> {
+        let mut exprs = vec![self.inner.clone()];
+        exprs.extend(js_exprs_to_exprs(other)?.into_vec());
+        let expr = dsl::concat_list(exprs).map_err(JsPolarsErr::from)?;
+        Ok(expr.into())
+    }
+
+    /// Run an expression on the values of every sublist.
+    /// Use `col("")` or `first()` to refer to the values of the sublist.
+    /// @example
+    /// ```js
+    /// > df.select(col("values").list.eval(col("").rank()))
+    /// ```
+    pub fn eval(&self, expr: &JsExpr) -> JsExpr {
+        self.list().eval(expr.inner.clone(), false).into()
+    }
+
+    /// Convert the sublists to structs.
+    /// @param nFieldStrategy - How to determine the number of fields:
+    /// - 'first_non_null' -> the length of the first sublist that is not null.
+    /// - 'max_width' -> the length of the longest sublist.
+    /// @param fields - Names of the fields, defaults to `field_0`, `field_1`, ...
+    /// @param upperBound - Number of fields in the schema, defaults to the number of names.
+    pub fn to_struct(
+        &self,
+        n_field_strategy: &str,
+        fields: Option<js_sys::Array>,
+        upper_bound: Option<usize>,
+    ) -> JsResult<JsExpr> {
+        let n_fields = match n_field_strategy {
+            "first_non_null" => ListToStructWidthStrategy::FirstNonNull,
+            "max_width" => ListToStructWidthStrategy::MaxWidth,
+            e => {
+                let msg = format!("n_field_strategy {} not implemented.", e);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
+        };
+        let names = fields
+            .as_ref()
+            .map(js_array_to_strings)
+            .transpose()?
+            .unwrap_or_default();
+        let upper_bound = upper_bound.unwrap_or(names.len());
+        let name_generator: Option<NameGenerator> = if names.is_empty() {
+            None
+        } else {
+            Some(Arc::new(move |idx: usize| match names.get(idx) {
+                Some(name) => name.as_str().into(),
+                None => _default_struct_name_gen(idx),
+            }))
+        };
+        Ok(self
+            .list()
+            .to_struct(n_fields, name_generator, upper_bound)
+            .into())
+    }
+}
+
+#[wasm_bindgen(js_name=SeriesListNameSpace)]
+pub struct JsSeriesListNameSpace {
+    pub(crate) series: JsSeries,
+}
+
+impl JsSeriesListNameSpace {
+    fn apply(&self, f: impl FnOnce(JsListNameSpace) -> JsResult<JsExpr>) -> JsResult<JsSeries> {
+        self.series.apply_expr(|inner| f(JsListNameSpace { inner }))
+    }
+}
+
+#[wasm_bindgen(js_class=SeriesListNameSpace)]
+impl JsSeriesListNameSpace {
+    /// Get the number of values in every sublist.
+    #[wasm_bindgen(js_name = len)]
+    pub fn lengths(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.lengths()))
+    }
+
+    /// Get the value by index in every sublist, negative indices count from the end.
+    pub fn get(&self, index: JsValue, null_on_oob: bool) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.get(index, null_on_oob))
+    }
+
+    pub fn first(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.first()))
+    }
+
+    pub fn last(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.last()))
+    }
+
+    pub fn slice(&self, offset: JsValue, length: JsValue) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.slice(offset, length))
+    }
+
+    pub fn contains(&self, item: JsValue) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.contains(item))
+    }
+
+    pub fn sum(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.sum()))
+    }
+
+    pub fn mean(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.mean()))
+    }
+
+    pub fn min(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.min()))
+    }
+
+    pub fn max(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.max()))
+    }
+
+    pub fn sort(&self, descending: bool, nulls_last: bool) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.sort(descending, nulls_last)))
+    }
+
+    pub fn reverse(&self) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.reverse()))
+    }
+
+    pub fn unique(&self, maintain_order: bool) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.unique(maintain_order)))
+    }
+
+    pub fn join(&self, separator: &str, ignore_nulls: bool) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.join(separator, ignore_nulls)))
+    }
+
+    /// Concat the sublists with the sublists or values of other series.
+    pub fn concat(&self, other: &js_sys::Array) -> JsResult<JsSeries> {
+        // select the series by position, its name may be a wildcard or regex for `col`
+        let mut exprs = vec![nth(0)];
+        let mut df = self.series.series.clone().into_frame();
+        for (i, s) in other.iter().enumerate() {
+            let s = js_value_to_series(&s)
+                .ok_or_else(|| JsPolarsErr::Other("expected an array of Series".into()))?;
+            let name = format!("__POLARS_CONCAT_{}", i);
+            exprs.push(col(&name));
+            df.with_column(s.with_name(name.as_str().into()))
+                .map_err(JsPolarsErr::from)?;
+        }
+        let expr = dsl::concat_list(exprs).map_err(JsPolarsErr::from)?;
+        let df = df
+            .lazy()
+            .select([expr])
+            .collect()
+            .map_err(JsPolarsErr::from)?;
+        Ok(df.get_columns()[0].clone().into())
+    }
+
+    /// Run an expression on the values of every sublist.
+    pub fn eval(&self, expr: &JsExpr) -> JsResult<JsSeries> {
+        self.apply(|ns| Ok(ns.eval(expr)))
+    }
+
+    pub fn to_struct(
+        &self,
+        n_field_strategy: &str,
+        fields: Option<js_sys::Array>,
+        upper_bound: Option<usize>,
+    ) -> JsResult<JsSeries> {
+        self.apply(|ns| ns.to_struct(n_field_strategy, fields, upper_bound))
+    }
+}
@@ -1,13 +1,28 @@
 pub mod conversion;
 
+pub mod categorical;
+pub mod datetime;
+pub mod list;
 pub mod string;
+pub mod struct_;
 
 pub use conversion::*;
 use polars::lazy::dsl;
 use polars::prelude::*;
 use wasm_bindgen::prelude::*;
 
+pub use self::categorical::{JsCatNameSpace, JsSeriesCatNameSpace};
+pub use self::datetime::{JsDateTimeNameSpace, JsSeriesDateTimeNameSpace};
+pub use self::list::{JsListNameSpace, JsSeriesListNameSpace};
 pub use self::string::JsStringNameSpace;
+pub use self::struct_::JsStructNameSpace;
+use crate::lazy::dataframe::js_exprs_to_exprs;
+use crate::lazy::lazy_functions::js_value_to_lit_expr;
+use crate::utils::{
+    js_value_to_polarstype, str_to_closed_window, str_to_duration, str_to_fill_null_strategy,
+    str_to_null_behavior, str_to_window_mapping,
+};
+use crate::JsResult;
 
 #[wasm_bindgen(js_name=Expr)]
 #[repr(transparent)]
@@ -25,6 +40,34 @@ impl JsExpr {
         }
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn cat(&self) -> JsCatNameSpace {
+        JsCatNameSpace {
+            inner: self.inner.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> JsDateTimeNameSpace {
+        JsDateTimeNameSpace {
+            inner: self.inner.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn list(&self) -> JsListNameSpace {
+        JsListNameSpace {
+            inner: self.inner.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter, js_name = "struct")]
+    pub fn struct_(&self) -> JsStructNameSpace {
+        JsStructNameSpace {
+            inner: self.inner.clone(),
+        }
+    }
+
     /// Take absolute values
     pub fn abs(&self) -> JsExpr {
         self.clone().inner.abs().into()
@@ -133,8 +176,16 @@ impl JsExpr {
     }
 
     /// Cast between data types.
-    pub fn cast() -> JsExpr {
-        todo!()
+    /// @param dtype - DataType to cast to, e.g. `DataType.Int64()` or `{ type: "Datetime", timeUnit: "ms" }`.
+    /// @param strict - Raise an error if a value cannot be cast instead of setting it to null.
+    pub fn cast(&self, dtype: JsValue, strict: bool) -> JsResult<JsExpr> {
+        let dtype = js_value_to_polarstype(&dtype)?;
+        let expr = if strict {
+            self.inner.clone().strict_cast(dtype)
+        } else {
+            self.inner.clone().cast(dtype)
+        };
+        Ok(expr.into())
     }
 
     /// Count the number of values in this expression
@@ -145,22 +196,22 @@ impl JsExpr {
     /// Calculate the n-th discrete difference.
     /// @param n - number of slots to shift
     /// @param nullBehavior -  'ignore' or 'drop'
-    pub fn diff(&self) -> JsExpr {
-        todo!()
+    pub fn diff(&self, n: i64, null_behavior: &str) -> JsResult<JsExpr> {
+        let null_behavior = str_to_null_behavior(null_behavior)?;
+        Ok(self.clone().inner.diff(n, null_behavior).into())
     }
 
     /// Compute the dot/inner product between two Expressions
     /// @param other Expression to compute dot product with
-    pub fn dot(&self, _other: &JsExpr) -> JsExpr {
-        todo!()
-        // self.inner.clone().dot(other.inner.clone()).into()
+    pub fn dot(&self, other: &JsExpr) -> JsExpr {
+        self.inner.clone().dot(other.inner.clone()).into()
     }
 
     ///  Exclude certain columns from a wildcard/regex selection.
     ///
     ///  You may also use regexes in the exclude list. They must start with `^` and end with `$`.
     ///
-    ///  @param columns Column(s) to exclude from selection
+    ///  @param columns Names or DataTypes of the column(s) to exclude from selection
     ///  @example
     ///  ```js
     ///   > df = pl.DataFrame({
@@ -198,8 +249,15 @@ impl JsExpr {
     ///  ╰─────┴──────╯
     ///  ```
     /// /
-    pub fn exclude(&self) -> JsExpr {
-        todo!()
+    pub fn exclude(&self, columns: &js_sys::Array) -> JsResult<JsExpr> {
+        let mut excluded = Vec::with_capacity(columns.length() as usize);
+        for column in columns.iter() {
+            match column.as_string() {
+                Some(name) => excluded.push(Excluded::Name(name.into())),
+                None => excluded.push(Excluded::Dtype(js_value_to_polarstype(&column)?)),
+            }
+        }
+        Ok(Expr::Exclude(Arc::new(self.inner.clone()), excluded).into())
     }
 
     /// Explode a list or utf8 Series.
@@ -207,11 +265,22 @@ impl JsExpr {
     pub fn explode(&self) -> JsExpr {
         self.clone().inner.explode().into()
     }
-    pub fn extend(&self) -> JsExpr {
-        todo!()
+
+    /// Alias for `extend_constant`.
+    pub fn extend(&self, value: JsValue, n: usize) -> JsResult<JsExpr> {
+        self.extend_constant(value, n)
     }
-    pub fn extend_constant(&self) -> JsExpr {
-        todo!()
+
+    /// Extend the column with `n` copies of a value.
+    /// @param value - Value or expression to extend with, `null` extends with nulls.
+    /// @param n - Number of values to add.
+    pub fn extend_constant(&self, value: JsValue, n: usize) -> JsResult<JsExpr> {
+        let value = js_value_to_lit_expr(value)?;
+        Ok(self
+            .inner
+            .clone()
+            .extend_constant(value, lit(n as u64))
+            .into())
     }
 
     /// Fill nan value with a fill value
@@ -393,13 +462,12 @@ impl JsExpr {
             .into()
     }
 
-    pub fn fill_null_with_strategy(&self) -> JsExpr {
-        todo!()
-        // self.inner
-        //     .clone()
-        //     .apply(move |s| s.fill_null(strategy.0), GetOutput::same_type())
-        //     .with_fmt("fill_null")
-        //     .into()
+    /// Fill null values with a strategy.
+    /// @param strategy - 'forward', 'backward', 'min', 'max', 'mean', 'zero' or 'one'.
+    /// @param limit - Number of consecutive nulls to fill for 'forward' and 'backward'.
+    pub fn fill_null_with_strategy(&self, strategy: &str, limit: Option<u32>) -> JsResult<JsExpr> {
+        let strategy = str_to_fill_null_strategy(strategy, limit)?;
+        Ok(self.clone().inner.fill_null_with_strategy(strategy).into())
     }
 
     pub fn drop_nulls(&self) -> JsExpr {
@@ -450,7 +518,77 @@ impl JsExpr {
     pub fn ceil(&self) -> JsExpr {
         self.clone().inner.ceil().into()
     }
-    pub fn clip(&self) -> JsExpr {
-        todo!()
+
+    /// Clip the values to the given bounds.
+    /// @param lower - Lower bound as a value or an expression, `null` leaves the values unbounded below.
+    /// @param upper - Upper bound as a value or an expression, `null` leaves the values unbounded above.
+    pub fn clip(&self, lower: JsValue, upper: JsValue) -> JsResult<JsExpr> {
+        let bound = |v: JsValue| -> JsResult<Option<Expr>> {
+            if v.is_null() || v.is_undefined() {
+                Ok(None)
+            } else {
+                js_value_to_lit_expr(v).map(Some)
+            }
+        };
+        let expr = self.inner.clone();
+        let expr = match (bound(lower)?, bound(upper)?) {
+            (Some(lower), Some(upper)) => expr.clip(lower, upper),
+            (Some(lower), None) => expr.clip_min(lower),
+            (None, Some(upper)) => expr.clip_max(upper),
+            (None, None) => expr,
+        };
+        Ok(expr.into())
+    }
+
+    /// Apply a window function over groups, keeping the number of rows.
+    /// @param partitionBy - Column names or expressions to group by.
+    /// @param orderBy - Column names or expressions that order the rows within each group.
+    /// @param mappingStrategy - How to map the results back to the rows:
+    /// - 'group_to_rows' -> map each value back to the row of its group.
+    /// - 'join' -> join the values of a group as a list on every row of the group.
+    /// - 'explode' -> explode the values, assuming the frame is sorted by the groups.
+    /// @example
+    /// ```js
+    /// > df.select(col("value").sum().over(["group"], null, "group_to_rows"))
+    /// ```
+    pub fn over(
+        &self,
+        partition_by: &js_sys::Array,
+        order_by: Option<js_sys::Array>,
+        mapping_strategy: &str,
+    ) -> JsResult<JsExpr> {
+        let mapping_strategy = str_to_window_mapping(mapping_strategy)?;
+        let partition_by = js_exprs_to_exprs(partition_by)?;
+        let order_by = match order_by {
+            Some(order_by) => Some((js_exprs_to_exprs(&order_by)?, SortOptions::default())),
+            None => None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .over_with_options(partition_by, order_by, mapping_strategy)
+            .into())
+    }
+
+    /// Create rolling groups based on a temporal or integer index column.
+    /// @param indexColumn - Sorted column that defines the windows.
+    /// @param period - Length of the window, e.g. '3d' or '2i'.
+    /// @param offset - Offset of the window, defaults to minus `period`.
+    /// @param closed - Which sides of the window are closed: 'left', 'right', 'both' or 'none'.
+    pub fn rolling(
+        &self,
+        index_column: &str,
+        period: &str,
+        offset: Option<String>,
+        closed: &str,
+    ) -> JsResult<JsExpr> {
+        let offset = offset.unwrap_or_else(|| format!("-{}", period));
+        let options = RollingGroupOptions {
+            index_column: index_column.into(),
+            period: str_to_duration(period)?,
+            offset: str_to_duration(&offset)?,
+            closed_window: str_to_closed_window(closed)?,
+        };
+        Ok(self.inner.clone().rolling(options).into())
     }
 }
@@ -3,6 +3,25 @@ use polars::prelude::*;
 use wasm_bindgen::prelude::*;
 
 use super::JsExpr;
+use crate::error::JsPolarsErr;
+use crate::utils::{js_value_to_polarstype, str_to_timeunit};
+use crate::JsResult;
+
+fn strptime_options(format: Option<String>, strict: bool, exact: bool) -> StrptimeOptions {
+    StrptimeOptions {
+        format: format.map(|f| f.into()),
+        strict,
+        exact,
+        cache: true,
+    }
+}
+
+fn lit_or_null<T: Literal>(value: Option<T>) -> Expr {
+    match value {
+        Some(value) => lit(value),
+        None => lit(Null {}),
+    }
+}
 
 #[wasm_bindgen(js_name=StringNameSpace)]
 pub struct JsStringNameSpace {
@@ -57,4 +76,221 @@ impl JsStringNameSpace {
             .with_fmt("str.lengths")
             .into()
     }
+
+    /// Replace the first match of a pattern.
+    /// @param pat - Regex pattern, or a literal string if `literal` is set.
+    /// @param value - Replacement, may refer to capture groups as `$1` when not literal.
+    pub fn replace(&self, pat: &str, value: &str, literal: bool) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .replace(lit(pat), lit(value), literal)
+            .into()
+    }
+
+    /// Replace all matches of a pattern.
+    /// @param pat - Regex pattern, or a literal string if `literal` is set.
+    /// @param value - Replacement, may refer to capture groups as `$1` when not literal.
+    pub fn replace_all(&self, pat: &str, value: &str, literal: bool) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .replace_all(lit(pat), lit(value), literal)
+            .into()
+    }
+
+    /// Split the strings into lists of substrings.
+    /// @param inclusive - Keep the separator at the end of every substring.
+    pub fn split(&self, by: &str, inclusive: bool) -> JsExpr {
+        let ns = self.inner.clone().str();
+        if inclusive {
+            ns.split_inclusive(lit(by)).into()
+        } else {
+            ns.split(lit(by)).into()
+        }
+    }
+
+    /// Split the strings into a struct of `n + 1` fields, padding with nulls.
+    /// @param inclusive - Keep the separator at the end of every substring.
+    pub fn split_exact(&self, by: &str, n: usize, inclusive: bool) -> JsExpr {
+        let ns = self.inner.clone().str();
+        if inclusive {
+            ns.split_exact_inclusive(lit(by), n).into()
+        } else {
+            ns.split_exact(lit(by), n).into()
+        }
+    }
+
+    /// Split the strings into a struct of at most `n` fields, the last field holding the remainder.
+    pub fn splitn(&self, by: &str, n: usize) -> JsExpr {
+        self.inner.clone().str().splitn(lit(by), n).into()
+    }
+
+    /// Remove leading and trailing characters.
+    /// @param matches - Characters to remove, `null` removes whitespace.
+    pub fn strip_chars(&self, matches: Option<String>) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .strip_chars(lit_or_null(matches))
+            .into()
+    }
+
+    /// Remove leading characters.
+    /// @param matches - Characters to remove, `null` removes whitespace.
+    pub fn strip_chars_start(&self, matches: Option<String>) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .strip_chars_start(lit_or_null(matches))
+            .into()
+    }
+
+    /// Remove trailing characters.
+    /// @param matches - Characters to remove, `null` removes whitespace.
+    pub fn strip_chars_end(&self, matches: Option<String>) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .strip_chars_end(lit_or_null(matches))
+            .into()
+    }
+
+    pub fn to_lowercase(&self) -> JsExpr {
+        self.inner.clone().str().to_lowercase().into()
+    }
+
+    pub fn to_uppercase(&self) -> JsExpr {
+        self.inner.clone().str().to_uppercase().into()
+    }
+
+    pub fn to_titlecase(&self) -> JsExpr {
+        self.inner.clone().str().to_titlecase().into()
+    }
+
+    /// Take a substring of every string.
+    /// @param offset - Start index in characters, negative indices count from the end.
+    /// @param length - Length of the substring, `null` takes the rest of the string.
+    pub fn slice(&self, offset: i64, length: Option<u32>) -> JsExpr {
+        let length = lit_or_null(length.map(|l| l as u64));
+        self.inner.clone().str().slice(lit(offset), length).into()
+    }
+
+    /// Pad the start of the strings until they reach the given length.
+    pub fn pad_start(&self, length: usize, fill_char: char) -> JsExpr {
+        self.inner.clone().str().pad_start(length, fill_char).into()
+    }
+
+    /// Pad the end of the strings until they reach the given length.
+    pub fn pad_end(&self, length: usize, fill_char: char) -> JsExpr {
+        self.inner.clone().str().pad_end(length, fill_char).into()
+    }
+
+    /// Pad the start of numeric strings with zeros, after any sign.
+    pub fn zfill(&self, length: usize) -> JsExpr {
+        self.inner.clone().str().zfill(lit(length as u64)).into()
+    }
+
+    /// Count the matches of a pattern in every string.
+    /// @param pat - Regex pattern, or a literal string if `literal` is set.
+    pub fn count_matches(&self, pat: &str, literal: bool) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .count_matches(lit(pat), literal)
+            .into()
+    }
+
+    /// Get the length of the strings in bytes.
+    pub fn len_bytes(&self) -> JsExpr {
+        self.inner.clone().str().len_bytes().into()
+    }
+
+    /// Get the length of the strings in characters.
+    pub fn len_chars(&self) -> JsExpr {
+        self.inner.clone().str().len_chars().into()
+    }
+
+    /// Parse the strings as integers.
+    /// @param base - Radix of the integers, e.g. 2, 10 or 16.
+    /// @param strict - Raise an error on strings that cannot be parsed instead of returning null.
+    pub fn to_integer(&self, base: u32, strict: bool) -> JsResult<JsExpr> {
+        if !(2..=36).contains(&base) {
+            let msg = format!("base {} is not in the range 2..=36", base);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .to_integer(lit(base), strict)
+            .into())
+    }
+
+    /// Parse the strings into a temporal DataType.
+    /// @param dtype - `Date`, `Datetime` or `Time`.
+    /// @param format - Chrono format string, e.g. '%Y-%m-%d'. Inferred when `null`.
+    /// @param strict - Raise an error on strings that cannot be parsed instead of returning null.
+    /// @param exact - Require an exact format match instead of searching the string for it.
+    pub fn strptime(
+        &self,
+        dtype: JsValue,
+        format: Option<String>,
+        strict: bool,
+        exact: bool,
+    ) -> JsResult<JsExpr> {
+        let dtype = js_value_to_polarstype(&dtype)?;
+        let options = strptime_options(format, strict, exact);
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .strptime(dtype, options, lit("raise"))
+            .into())
+    }
+
+    /// Parse the strings into dates.
+    /// @param format - Chrono format string, e.g. '%Y-%m-%d'. Inferred when `null`.
+    pub fn to_date(&self, format: Option<String>, strict: bool, exact: bool) -> JsExpr {
+        let options = strptime_options(format, strict, exact);
+        self.inner.clone().str().to_date(options).into()
+    }
+
+    /// Parse the strings into datetimes.
+    /// @param format - Chrono format string, e.g. '%Y-%m-%d %H:%M:%S'. Inferred when `null`.
+    /// @param timeUnit - 'ns', 'us' or 'ms', inferred from the format when `null`.
+    /// @param timeZone - Time zone of the parsed datetimes.
+    pub fn to_datetime(
+        &self,
+        format: Option<String>,
+        time_unit: Option<String>,
+        time_zone: Option<String>,
+        strict: bool,
+        exact: bool,
+    ) -> JsResult<JsExpr> {
+        let time_unit = time_unit.map(|tu| str_to_timeunit(&tu)).transpose()?;
+        let options = strptime_options(format, strict, exact);
+        Ok(self
+            .inner
+            .clone()
+            .str()
+            .to_datetime(
+                time_unit,
+                time_zone.map(|tz| tz.into()),
+                options,
+                lit("raise"),
+            )
+            .into())
+    }
+
+    /// Concat all the strings of the column into a single string.
+    /// @param delimiter - String placed between the values.
+    /// @param ignoreNulls - Skip null values instead of returning null.
+    pub fn concat(&self, delimiter: &str, ignore_nulls: bool) -> JsExpr {
+        self.inner
+            .clone()
+            .str()
+            .join(delimiter, ignore_nulls)
+            .into()
+    }
 }
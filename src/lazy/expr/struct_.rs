@@ -0,0 +1,32 @@
+use polars::lazy::dsl::Expr;
+use wasm_bindgen::prelude::*;
+
+use super::JsExpr;
+use crate::utils::js_array_to_strings;
+use crate::JsResult;
+
+#[wasm_bindgen(js_name=StructNameSpace)]
+pub struct JsStructNameSpace {
+    pub(crate) inner: Expr,
+}
+
+#[wasm_bindgen(js_class=StructNameSpace)]
+impl JsStructNameSpace {
+    /// Retrieve a field of the struct as a column.
+    /// @param name - Name of the field.
+    pub fn field(&self, name: &str) -> JsExpr {
+        self.inner.clone().struct_().field_by_name(name).into()
+    }
+
+    /// Rename the fields of the struct.
+    /// @param names - New names, in the order of the fields.
+    pub fn rename_fields(&self, names: &js_sys::Array) -> JsResult<JsExpr> {
+        let names = js_array_to_strings(names)?;
+        Ok(self.inner.clone().struct_().rename_fields(names).into())
+    }
+
+    /// Serialize every struct to a JSON string.
+    pub fn json_encode(&self) -> JsExpr {
+        self.inner.clone().struct_().json_encode().into()
+    }
+}
@@ -2,7 +2,9 @@ use polars::lazy::dsl;
 use polars::prelude::*;
 use wasm_bindgen::prelude::*;
 
-use super::expr::JsExpr;
+use super::dataframe::js_exprs_to_exprs;
+use super::expr::{js_value_to_expr, JsExpr};
+use crate::{conversion::FromJsValue, error::JsPolarsErr, utils::js_value_to_polarstype, JsResult};
 
 #[wasm_bindgen]
 #[derive(Clone)]
@@ -198,3 +200,54 @@ pub fn cols(names: JsValue) -> JsExpr {
     let names: Vec<String> = serde_wasm_bindgen::from_value(names).unwrap();
     dsl::cols(names).into()
 }
+
+/// Collect the columns of the expressions into a single struct column.
+/// @param exprs - Column names or expressions, each becoming a field of the struct.
+/// @example
+/// ```js
+/// > df.select(pl.struct([col("a"), col("b")]).alias("ab"))
+/// ```
+#[wasm_bindgen(js_name = "struct")]
+pub fn as_struct(exprs: &js_sys::Array) -> JsResult<JsExpr> {
+    let exprs = js_exprs_to_exprs(exprs)?;
+    Ok(dsl::as_struct(exprs.into_vec()).into())
+}
+
+/// __A literal value.__
+///
+/// Accepts numbers, bigints, strings, booleans, `null`, `Date`s, typed arrays and `Series`.
+/// Numbers are dynamically typed and take the type of the column they are compared with.
+/// @param value - The value of the literal.
+/// @param dtype - Optional DataType to cast the literal to.
+/// @example
+/// ```js
+/// > df.filter(col("foo").gt(lit(2)))
+/// > df.with_column(lit(new Date(), "Date").alias("today"))
+/// ```
+#[wasm_bindgen]
+pub fn lit(value: JsValue, dtype: JsValue) -> JsResult<JsExpr> {
+    let expr = js_value_to_lit_expr(value)?;
+    let expr = if dtype.is_null() || dtype.is_undefined() {
+        expr
+    } else {
+        expr.strict_cast(js_value_to_polarstype(&dtype)?)
+    };
+    Ok(expr.into())
+}
+
+/// Take the expression out of an `Expr`, or make a literal of any other value.
+pub(crate) fn js_value_to_lit_expr(value: JsValue) -> JsResult<Expr> {
+    if let Some(expr) = js_value_to_expr(&value) {
+        return Ok(expr);
+    }
+    let is_number = value.as_f64().is_some();
+    let expr = match AnyValue::try_from_js(value)? {
+        AnyValue::Float64(n) if is_number && n.fract() == 0.0 && n.abs() < 2f64.powi(53) => {
+            dsl::lit(LiteralValue::Int(n as i128))
+        }
+        AnyValue::Float64(n) if is_number => dsl::lit(LiteralValue::Float(n)),
+        AnyValue::List(series) => dsl::lit(series),
+        av => dsl::lit(LiteralValue::try_from(av).map_err(JsPolarsErr::from)?),
+    };
+    Ok(expr)
+}
@@ -2,7 +2,12 @@ use polars::prelude::*;
 use wasm_bindgen::JsCast;
 
 use crate::{
-    conversion::Wrap, dataframe::JsDataFrame, error::JsPolarsErr, extern_iterator, extern_struct,
+    conversion::{js_array_to_series, Wrap}, dataframe::JsDataFrame, datatypes::JsDataType,
+    error::JsPolarsErr, extern_iterator, extern_struct,
+    lazy::expr::{
+        JsExpr, JsSeriesCatNameSpace, JsSeriesDateTimeNameSpace, JsSeriesListNameSpace,
+    },
+    utils::js_value_to_polarstype,
     JsResult,
 };
 
@@ -19,6 +24,26 @@ impl JsSeries {
     pub(crate) fn new(series: Series) -> Self {
         JsSeries { series }
     }
+
+    /// Evaluate an expression on this series, which is passed to `f` as the only column.
+    ///
+    /// The column is selected by position, as `col(name)` would expand names such as `*`
+    /// or `^a.*$` as a wildcard or regex.
+    pub(crate) fn apply_expr(
+        &self,
+        f: impl FnOnce(Expr) -> JsResult<JsExpr>,
+    ) -> JsResult<JsSeries> {
+        let expr = f(nth(0))?;
+        let df = self
+            .series
+            .clone()
+            .into_frame()
+            .lazy()
+            .select([expr.inner])
+            .collect()
+            .map_err(JsPolarsErr::from)?;
+        Ok(df.get_columns()[0].clone().into())
+    }
 }
 
 impl From<Series> for JsSeries {
@@ -62,6 +87,37 @@ impl JsSeries {
     pub fn wrap(ptr: u32) -> JsSeries {
         unsafe { JsSeries::from_abi(ptr) }
     }
+
+    /// Create a series from a JS array.
+    /// @param name - Name of the series.
+    /// @param values - Values of the series, `null` and `undefined` become null values.
+    /// Numbers, bigints, strings, booleans, `Date`s, arrays and objects are converted to
+    /// Float64, Int64/UInt64, String, Bool, Datetime, List and Struct values.
+    /// @param dtype - DataType of the series, inferred from the values if omitted.
+    /// @param strict - Raise an error if a value does not match the dtype, instead of
+    /// casting it to the supertype of the values or setting it to null.
+    /// @example
+    /// ```js
+    /// > Series.from("a", [1, null, 3], DataType.Int16(), true)
+    /// > Series.from("b", [new Date(), null], DataType.Date(), true)
+    /// > Series.from("c", ["x", "y", "x"], DataType.Categorical(), true)
+    /// ```
+    #[wasm_bindgen(js_name = from)]
+    pub fn from_values(
+        name: &str,
+        values: &js_sys::Array,
+        dtype: JsValue,
+        strict: bool,
+    ) -> JsResult<JsSeries> {
+        let dtype = if dtype.is_null() || dtype.is_undefined() {
+            None
+        } else {
+            Some(js_value_to_polarstype(&dtype)?)
+        };
+        let series = js_array_to_series(name, values, dtype.as_ref(), strict)?;
+        Ok(JsSeries { series })
+    }
+
     pub fn new_str(name: &str, values: &js_sys::Array) -> JsResult<JsSeries> {
         let series =
             StringChunked::from_iter_options(name.into(), values.iter().map(|v| v.as_string()))
@@ -150,9 +206,9 @@ impl JsSeries {
         s.with_name(name.into()).into()
     }
 
-    pub fn new_opt_bool_array(name: String, arr: js_sys::Array) -> JsSeries {
-        let s: Series = arr.iter().map(|v| v.as_bool()).collect();
-        s.with_name(name.into()).into()
+    pub fn new_opt_bool_array(name: String, arr: js_sys::Array) -> JsResult<JsSeries> {
+        let series = js_array_to_series(&name, &arr, Some(&DataType::Boolean), true)?;
+        Ok(JsSeries { series })
     }
 
     pub fn get_idx(&self, idx: usize) -> JsResult<JsValue> {
@@ -468,12 +524,32 @@ impl JsSeries {
         self.series.len() as i64
     }
 
+    /// Cast between data types.
+    /// @param dtype - DataType to cast to, e.g. `DataType.Int64()` or `{ type: "List", inner: "Int64" }`.
+    /// @param strict - Raise an error if a value cannot be cast instead of setting it to null.
+    pub fn cast(&self, dtype: JsValue, strict: bool) -> JsResult<JsSeries> {
+        let dtype = js_value_to_polarstype(&dtype)?;
+        let out = if strict {
+            self.series.strict_cast(&dtype)
+        } else {
+            self.series.cast(&dtype)
+        };
+        let out = out.map_err(JsPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     pub fn to_physical(&self) -> JsSeries {
         let s = self.series.to_physical_repr().into_owned();
         s.into()
     }
+    /// Convert the series to a JS array.
+    /// Lists become nested arrays, structs become objects and temporal values become `Date`s.
     pub fn to_list(&self) -> JsValue {
-        todo!()
+        let s = self.series.rechunk();
+        s.iter()
+            .map(|av| JsValue::from(Wrap(av)))
+            .collect::<js_sys::Array>()
+            .into()
     }
     pub fn median(&self) -> Option<f64> {
         match self.series.dtype() {
@@ -522,6 +598,27 @@ impl JsSeries {
         Ok(JsSeries::new(s))
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn cat(&self) -> JsSeriesCatNameSpace {
+        JsSeriesCatNameSpace {
+            series: self.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn dt(&self) -> JsSeriesDateTimeNameSpace {
+        JsSeriesDateTimeNameSpace {
+            series: self.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn list(&self) -> JsSeriesListNameSpace {
+        JsSeriesListNameSpace {
+            series: self.clone(),
+        }
+    }
+
     pub fn arr_lengths(&self) -> JsResult<JsSeries> {
         let ca = self.series.list().map_err(JsPolarsErr::from)?;
         let s = ca.lst_lengths().into_series();
@@ -533,34 +630,101 @@ impl JsSeries {
         Ok(n)
     }
 
-    pub fn is_first(&self) -> JsResult<JsSeries> {
-        todo!()
-        // let out = self
-        //     .series
-        //     .is_first()
-        //     .map_err(JsPolarsErr::from)?
-        //     .into_series();
-        // Ok(out.into())
+    /// Get a mask of the first occurrence of every distinct value.
+    pub fn is_first_distinct(&self) -> JsResult<JsSeries> {
+        self.apply_expr(|e| Ok(e.is_first_distinct().into()))
+    }
+
+    /// Get a mask of the last occurrence of every distinct value.
+    pub fn is_last_distinct(&self) -> JsResult<JsSeries> {
+        self.apply_expr(|e| Ok(e.is_last_distinct().into()))
     }
 
     pub fn shrink_to_fit(&mut self) {
         self.series.shrink_to_fit();
     }
 
-    pub fn dot(&self, _other: &JsSeries) -> Option<f64> {
-        todo!()
-        // self.series.dot(&other.series)
+    /// Compute the dot/inner product with another numeric series.
+    pub fn dot(&self, other: &JsSeries) -> JsResult<f64> {
+        let out = self.series.dot(&other.series).map_err(JsPolarsErr::from)?;
+        Ok(out)
     }
 
-    pub fn dtype(&self) -> String {
-        let dt: crate::datatypes::JsDataType = self.series.dtype().into();
-        dt.to_string()
+    pub fn dtype(&self) -> JsDataType {
+        self.series.dtype().into()
     }
-    pub fn inner_dtype(&self) -> Option<String> {
-        self.series.dtype().inner_dtype().map(|dt| {
-            let dt: crate::datatypes::JsDataType = dt.into();
-            dt.to_string()
-        })
+    pub fn inner_dtype(&self) -> Option<JsDataType> {
+        self.series.dtype().inner_dtype().map(|dt| dt.into())
+    }
+
+    /// Get the values as a TypedArray, e.g. a `Float64Array` for `Float64` or a `BigInt64Array` for `Int64`.
+    /// Temporal types return their physical values and booleans return a `Uint8Array`.
+    ///
+    /// If the series is a single chunk without nulls, the array is a view into the wasm memory
+    /// that is only valid as long as the series is alive and not modified.
+    /// Otherwise the values are copied and null values are set to 0, use `validity` to find them.
+    pub fn to_typed_array(&self) -> JsResult<JsValue> {
+        let s = self.series.to_physical_repr();
+        let arr: JsValue = match s.dtype() {
+            DataType::Boolean => {
+                let ca = s.bool().map_err(JsPolarsErr::from)?;
+                let values: Vec<u8> = ca.iter().map(|v| v.unwrap_or(false) as u8).collect();
+                js_sys::Uint8Array::from(values.as_slice()).into()
+            }
+            DataType::UInt8 => to_typed_array(s.u8().unwrap(), js_sys::Uint8Array::view).into(),
+            DataType::UInt16 => to_typed_array(s.u16().unwrap(), js_sys::Uint16Array::view).into(),
+            DataType::UInt32 => to_typed_array(s.u32().unwrap(), js_sys::Uint32Array::view).into(),
+            DataType::UInt64 => {
+                to_typed_array(s.u64().unwrap(), js_sys::BigUint64Array::view).into()
+            }
+            DataType::Int8 => to_typed_array(s.i8().unwrap(), js_sys::Int8Array::view).into(),
+            DataType::Int16 => to_typed_array(s.i16().unwrap(), js_sys::Int16Array::view).into(),
+            DataType::Int32 => to_typed_array(s.i32().unwrap(), js_sys::Int32Array::view).into(),
+            DataType::Int64 => to_typed_array(s.i64().unwrap(), js_sys::BigInt64Array::view).into(),
+            DataType::Float32 => {
+                to_typed_array(s.f32().unwrap(), js_sys::Float32Array::view).into()
+            }
+            DataType::Float64 => {
+                to_typed_array(s.f64().unwrap(), js_sys::Float64Array::view).into()
+            }
+            dt => {
+                let msg = format!("to_typed_array is not supported for dtype {}.", dt);
+                return Err(JsPolarsErr::Other(msg).into());
+            }
+        };
+        Ok(arr)
+    }
+
+    /// Get the validity as a `Uint8Array` with 1 for valid and 0 for null values.
+    /// Returns `undefined` if the series contains no nulls.
+    pub fn validity(&self) -> Option<js_sys::Uint8Array> {
+        if self.series.null_count() == 0 {
+            return None;
+        }
+        let mask: Vec<u8> = self
+            .series
+            .is_not_null()
+            .into_no_null_iter()
+            .map(|valid| valid as u8)
+            .collect();
+        Some(js_sys::Uint8Array::from(mask.as_slice()))
+    }
+}
+
+/// View the values of `ca` as a TypedArray, or copy them if they are not contiguous.
+fn to_typed_array<T, A>(ca: &ChunkedArray<T>, view: unsafe fn(&[T::Native]) -> A) -> A
+where
+    T: PolarsNumericType,
+    A: for<'a> From<&'a [T::Native]>,
+{
+    match ca.cont_slice() {
+        // SAFETY: the view is only valid while the series is alive, which is documented
+        // on `to_typed_array`.
+        Ok(values) => unsafe { view(values) },
+        Err(_) => {
+            let values: Vec<T::Native> = ca.iter().map(|v| v.unwrap_or_default()).collect();
+            A::from(values.as_slice())
+        }
     }
 }
 
@@ -581,6 +745,23 @@ impl JsSeries {
 //         )),
 //     }
 // }
+thread_local! {
+    /// Prototype of the exported `Series` class, taken from a throwaway instance.
+    static SERIES_PROTOTYPE: js_sys::Object =
+        js_sys::Object::get_prototype_of(&JsSeries::from(Series::default()).into());
+}
+
+/// Clone the series out of a JS `Series` object, if `jsv` is one.
+pub(crate) fn js_value_to_series(jsv: &JsValue) -> Option<Series> {
+    use crate::conversion::extern_struct::{is_live_instance, RefRustStruct};
+    if !SERIES_PROTOTYPE.with(|proto| is_live_instance(jsv, proto)) {
+        return None;
+    }
+    let ser: &ExternSeries = jsv.unchecked_ref();
+    let series = ser.ref_rust().series.clone();
+    Some(series)
+}
+
 pub(crate) fn to_series_collection(iter: js_sys::Iterator) -> Vec<Series> {
     let cols: Vec<Series> = iter
         .into_iter()
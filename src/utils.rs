@@ -1,28 +1,318 @@
-// use polars::prelude::{DataType, TimeUnit};
-// use wasm_bindgen::convert::FromWasmAbi;
-
-// use crate::{JsResult, lazy::expr::JsExpr};
-
-// pub fn str_to_polarstype(s: &str) -> DataType {
-//     match s {
-//         "UInt8" => DataType::UInt8,
-//         "UInt16" => DataType::UInt16,
-//         "UInt32" => DataType::UInt32,
-//         "UInt64" => DataType::UInt64,
-//         "Int8" => DataType::Int8,
-//         "Int16" => DataType::Int16,
-//         "Int32" => DataType::Int32,
-//         "Int64" => DataType::Int64,
-//         "Float32" => DataType::Float32,
-//         "Float64" => DataType::Float64,
-//         "Boolean" => DataType::Boolean,
-//         "Utf8" => DataType::Utf8,
-//         "Date" => DataType::Date,
-//         "Datetime" => DataType::Datetime(TimeUnit::Milliseconds, None),
-//         "Duration" => DataType::Duration(TimeUnit::Milliseconds),
-//         "Time" => DataType::Time,
-//         "List" => DataType::List(DataType::Null.into()),
-//         "Categorical" => DataType::Categorical(None),
-//         tp => panic!("Type {} not implemented in str_to_polarstype", tp),
-//     }
-// }
+use polars::export::arrow::array::Utf8ViewArray;
+use polars::prelude::{
+    create_enum_data_type, CategoricalOrdering, ClosedWindow, DataType, Duration, Field,
+    FillNullStrategy, IdxSize, NullBehavior, QuantileInterpolOptions, Schema, TimeUnit,
+    WindowMapping,
+};
+use wasm_bindgen::prelude::*;
+
+use crate::{error::JsPolarsErr, JsResult};
+
+pub fn str_to_polarstype(s: &str) -> JsResult<DataType> {
+    let dt = match s {
+        "UInt8" => DataType::UInt8,
+        "UInt16" => DataType::UInt16,
+        "UInt32" => DataType::UInt32,
+        "UInt64" => DataType::UInt64,
+        "Int8" => DataType::Int8,
+        "Int16" => DataType::Int16,
+        "Int32" => DataType::Int32,
+        "Int64" => DataType::Int64,
+        "Float32" => DataType::Float32,
+        "Float64" => DataType::Float64,
+        "Bool" | "Boolean" => DataType::Boolean,
+        "String" | "Utf8" => DataType::String,
+        "Date" => DataType::Date,
+        "Datetime" => DataType::Datetime(TimeUnit::Milliseconds, None),
+        "Duration" => DataType::Duration(TimeUnit::Milliseconds),
+        "Time" => DataType::Time,
+        "Decimal" => DataType::Decimal(None, None),
+        "Binary" => DataType::Binary,
+        "List" => DataType::List(DataType::Null.into()),
+        "Categorical" => DataType::Categorical(None, Default::default()),
+        "Null" => DataType::Null,
+        "Unknown" => DataType::Unknown(Default::default()),
+        tp => {
+            let msg = format!("Type {} not implemented in str_to_polarstype", tp);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(dt)
+}
+
+pub fn str_to_timeunit(s: &str) -> JsResult<TimeUnit> {
+    let tu = match s {
+        "ns" => TimeUnit::Nanoseconds,
+        "us" => TimeUnit::Microseconds,
+        "ms" => TimeUnit::Milliseconds,
+        tu => {
+            let msg = format!("time unit {} not implemented.", tu);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(tu)
+}
+
+pub fn str_to_categorical_ordering(s: &str) -> JsResult<CategoricalOrdering> {
+    let ordering = match s {
+        "physical" => CategoricalOrdering::Physical,
+        "lexical" => CategoricalOrdering::Lexical,
+        ordering => {
+            let msg = format!("categorical ordering {} not implemented.", ordering);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(ordering)
+}
+
+pub fn str_to_null_behavior(s: &str) -> JsResult<NullBehavior> {
+    let null_behavior = match s {
+        "ignore" => NullBehavior::Ignore,
+        "drop" => NullBehavior::Drop,
+        null_behavior => {
+            let msg = format!("null_behavior {} not implemented.", null_behavior);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(null_behavior)
+}
+
+pub fn str_to_quantile_interpolation(s: &str) -> JsResult<QuantileInterpolOptions> {
+    let interpolation = match s {
+        "nearest" => QuantileInterpolOptions::Nearest,
+        "lower" => QuantileInterpolOptions::Lower,
+        "higher" => QuantileInterpolOptions::Higher,
+        "midpoint" => QuantileInterpolOptions::Midpoint,
+        "linear" => QuantileInterpolOptions::Linear,
+        interpolation => {
+            let msg = format!("interpolation {} not implemented.", interpolation);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(interpolation)
+}
+
+pub fn str_to_window_mapping(s: &str) -> JsResult<WindowMapping> {
+    let mapping = match s {
+        "group_to_rows" => WindowMapping::GroupsToRows,
+        "join" => WindowMapping::Join,
+        "explode" => WindowMapping::Explode,
+        mapping => {
+            let msg = format!("mapping strategy {} not implemented.", mapping);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(mapping)
+}
+
+pub fn str_to_closed_window(s: &str) -> JsResult<ClosedWindow> {
+    let closed = match s {
+        "left" => ClosedWindow::Left,
+        "right" => ClosedWindow::Right,
+        "both" => ClosedWindow::Both,
+        "none" => ClosedWindow::None,
+        closed => {
+            let msg = format!("closed window {} not implemented.", closed);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(closed)
+}
+
+/// Parse a duration such as `3d12h` or `-2i`, checking the string first as
+/// `Duration::parse` panics on invalid input.
+pub fn str_to_duration(s: &str) -> JsResult<Duration> {
+    let mut rest = s.strip_prefix('-').unwrap_or(s);
+    let mut valid = !rest.is_empty();
+    while valid && !rest.is_empty() {
+        let n_digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (n, tail) = rest.split_at(n_digits);
+        let n_letters = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(n_letters);
+        valid = n.parse::<i64>().is_ok()
+            && matches!(
+                unit,
+                "ns" | "us" | "ms" | "s" | "m" | "h" | "d" | "w" | "mo" | "q" | "y" | "i"
+            );
+        rest = tail;
+    }
+    if !valid {
+        let msg = format!("invalid duration {:?}", s);
+        return Err(JsPolarsErr::Other(msg).into());
+    }
+    Ok(Duration::parse(s))
+}
+
+pub fn str_to_fill_null_strategy(s: &str, limit: Option<u32>) -> JsResult<FillNullStrategy> {
+    let strategy = match s {
+        "forward" => FillNullStrategy::Forward(limit.map(|l| l as IdxSize)),
+        "backward" => FillNullStrategy::Backward(limit.map(|l| l as IdxSize)),
+        "min" => FillNullStrategy::Min,
+        "max" => FillNullStrategy::Max,
+        "mean" => FillNullStrategy::Mean,
+        "zero" => FillNullStrategy::Zero,
+        "one" => FillNullStrategy::One,
+        strategy => {
+            let msg = format!("fill_null strategy {} not implemented.", strategy);
+            return Err(JsPolarsErr::Other(msg).into());
+        }
+    };
+    Ok(strategy)
+}
+
+fn get_key(obj: &JsValue, key: &str) -> JsResult<Option<JsValue>> {
+    let value = js_sys::Reflect::get(obj, &JsValue::from_str(key))?;
+    if value.is_null() || value.is_undefined() {
+        Ok(None)
+    } else {
+        Ok(Some(value))
+    }
+}
+
+/// Parse a dtype descriptor.
+///
+/// This is either the name of the type, e.g. `"Int64"`, or an object with the name
+/// under `type` and the parameters of the type, e.g.
+/// `{ type: "Datetime", timeUnit: "ms", timeZone: "UTC" }`,
+/// `{ type: "List", inner: "Int64" }`,
+/// `{ type: "Enum", categories: ["a", "b"] }` or
+/// `{ type: "Struct", fields: [{ name: "a", dtype: "Int64" }] }`.
+/// A `DataType` instance is accepted as well, through its `toJSON` descriptor.
+pub fn js_value_to_polarstype(v: &JsValue) -> JsResult<DataType> {
+    if let Some(s) = v.as_string() {
+        return str_to_polarstype(&s);
+    }
+    if !v.is_object() {
+        return Err(JsPolarsErr::Other(format!("invalid dtype {:?}", v)).into());
+    }
+    if let Some(to_json) = get_key(v, "toJSON")? {
+        let to_json: &js_sys::Function = to_json.unchecked_ref();
+        return js_value_to_polarstype(&to_json.call0(v)?);
+    }
+    let tp = get_key(v, "type")?
+        .and_then(|tp| tp.as_string())
+        .ok_or_else(|| JsPolarsErr::Other("dtype object is missing its type".into()))?;
+    let time_unit = match get_key(v, "timeUnit")?.and_then(|tu| tu.as_string()) {
+        Some(tu) => str_to_timeunit(&tu)?,
+        None => TimeUnit::Milliseconds,
+    };
+
+    let ordering = match get_key(v, "ordering")?.and_then(|o| o.as_string()) {
+        Some(ordering) => str_to_categorical_ordering(&ordering)?,
+        None => CategoricalOrdering::Physical,
+    };
+    let get_usize = |key: &str| -> JsResult<Option<usize>> {
+        Ok(get_key(v, key)?
+            .and_then(|n| n.as_f64())
+            .map(|n| n as usize))
+    };
+
+    let dt = match tp.as_ref() {
+        "Datetime" => {
+            let tz = get_key(v, "timeZone")?.and_then(|tz| tz.as_string());
+            DataType::Datetime(time_unit, tz.map(|tz| tz.into()))
+        }
+        "Duration" => DataType::Duration(time_unit),
+        "Decimal" => DataType::Decimal(get_usize("precision")?, get_usize("scale")?),
+        "Array" => {
+            let inner = match get_key(v, "inner")? {
+                Some(inner) => js_value_to_polarstype(&inner)?,
+                None => DataType::Null,
+            };
+            let width = get_usize("width")?
+                .ok_or_else(|| JsPolarsErr::Other("array dtype is missing its width".into()))?;
+            DataType::Array(Box::new(inner), width)
+        }
+        "List" => {
+            let inner = match get_key(v, "inner")? {
+                Some(inner) => js_value_to_polarstype(&inner)?,
+                None => DataType::Null,
+            };
+            DataType::List(Box::new(inner))
+        }
+        "Struct" => {
+            let fields = get_key(v, "fields")?.unwrap_or_else(|| js_sys::Array::new().into());
+            DataType::Struct(js_value_to_fields(&fields)?)
+        }
+        "Categorical" => DataType::Categorical(None, ordering),
+        "Enum" => {
+            let categories = get_key(v, "categories")?
+                .ok_or_else(|| JsPolarsErr::Other("enum dtype is missing its categories".into()))?;
+            let categories = js_sys::Array::from(&categories)
+                .iter()
+                .map(|c| {
+                    c.as_string().ok_or_else(|| {
+                        JsPolarsErr::Other(format!("enum category {:?} is not a string", c)).into()
+                    })
+                })
+                .collect::<JsResult<Vec<String>>>()?;
+            match create_enum_data_type(Utf8ViewArray::from_slice_values(categories)) {
+                DataType::Enum(rev_map, _) => DataType::Enum(rev_map, ordering),
+                dt => dt,
+            }
+        }
+        tp => str_to_polarstype(tp)?,
+    };
+    Ok(dt)
+}
+
+/// Struct fields are either an array of `{ name, dtype }` objects, or an object of
+/// `{ name: dtype }`.
+fn js_value_to_fields(v: &JsValue) -> JsResult<Vec<Field>> {
+    if js_sys::Array::is_array(v) {
+        let fields: &js_sys::Array = v.unchecked_ref();
+        fields
+            .iter()
+            .map(|field| {
+                let name = get_key(&field, "name")?
+                    .and_then(|name| name.as_string())
+                    .ok_or_else(|| JsPolarsErr::Other("struct field is missing its name".into()))?;
+                let dtype = get_key(&field, "dtype")?.ok_or_else(|| {
+                    JsPolarsErr::Other(format!("struct field {} is missing its dtype", name))
+                })?;
+                Ok(Field::new(name.into(), js_value_to_polarstype(&dtype)?))
+            })
+            .collect()
+    } else {
+        Ok(js_object_to_schema(v)?.iter_fields().collect())
+    }
+}
+
+/// Convert a JS object of `{ columnName: dtype }` into a `Schema`, keeping key order.
+pub fn js_object_to_schema(obj: &JsValue) -> JsResult<Schema> {
+    let entries = js_sys::Object::entries(obj.unchecked_ref());
+    let mut schema = Schema::with_capacity(entries.length() as usize);
+    for entry in entries.iter() {
+        let entry: js_sys::Array = entry.unchecked_into();
+        let name = entry.get(0).as_string().ok_or_else(|| {
+            let msg = format!("expected a string column name, got {:?}", entry.get(0));
+            JsPolarsErr::Other(msg)
+        })?;
+        let dtype = js_value_to_polarstype(&entry.get(1))?;
+        schema.with_column(name.into(), dtype);
+    }
+    Ok(schema)
+}
+
+/// Convert a single character option, e.g. a CSV separator, into a byte.
+pub fn char_to_ascii_byte(c: char, option: &str) -> JsResult<u8> {
+    if !c.is_ascii() {
+        let msg = format!("{} must be an ASCII character, got {:?}", option, c);
+        return Err(JsPolarsErr::Other(msg).into());
+    }
+    Ok(c as u8)
+}
+
+/// Convert a JS array of strings, e.g. column names, into a `Vec<String>`.
+pub fn js_array_to_strings(arr: &js_sys::Array) -> JsResult<Vec<String>> {
+    arr.iter()
+        .map(|v| {
+            v.as_string()
+                .ok_or_else(|| JsPolarsErr::Other(format!("expected a string, got {:?}", v)).into())
+        })
+        .collect()
+}